@@ -1,55 +1,60 @@
-use advent_of_code_2022::days::*;
+use advent_of_code_2022::days::get_days;
+use advent_of_code_2022::input::{normalise, InputSource};
+use advent_of_code_2022::params::DayParams;
+use advent_of_code_2022::Runnable;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
+// DAYS takes the same day selection as the binary, e.g. `DAYS="1-15 21 2022/3"`
+// BENCH_INPUTS is one of `real`, `test` or `both` (the default)
 fn bench_days(c: &mut Criterion) {
-    // c.bench_function("day 01", |b| {
-    //     b.iter(|| day01::run(black_box(include_str!("../input/real/01.txt")), false))
-    // });
-    // c.bench_function("day 02", |b| {
-    //     b.iter(|| day02::run(black_box(include_str!("../input/real/02.txt")), false))
-    // });
-    // c.bench_function("day 03", |b| {
-    //     b.iter(|| day03::run(black_box(include_str!("../input/real/03.txt")), false))
-    // });
-    // c.bench_function("day 04", |b| {
-    //     b.iter(|| day04::run(black_box(include_str!("../input/real/04.txt")), false))
-    // });
-    // c.bench_function("day 05", |b| {
-    //     b.iter(|| day05::run(black_box(include_str!("../input/real/05.txt")), false))
-    // });
-    // c.bench_function("day 06", |b| {
-    //     b.iter(|| day06::run(black_box(include_str!("../input/real/06.txt")), false))
-    // });
-    // c.bench_function("day 07", |b| {
-    //     b.iter(|| day07::run(black_box(include_str!("../input/real/07.txt")), false))
-    // });
-    // c.bench_function("day 08", |b| {
-    //     b.iter(|| day08::run(black_box(include_str!("../input/real/08.txt")), false))
-    // });
-    // c.bench_function("day 09", |b| {
-    //     b.iter(|| day09::run(black_box(include_str!("../input/real/09.txt")), false))
-    // });
-    // c.bench_function("day 10", |b| {
-    //     b.iter(|| day10::run(black_box(include_str!("../input/real/10.txt")), false))
-    // });
-    // c.bench_function("day 11", |b| {
-    //     b.iter(|| day11::run(black_box(include_str!("../input/real/11.txt")), false))
-    // });
-    // c.bench_function("day 12", |b| {
-    //     b.iter(|| day12::run(black_box(include_str!("../input/real/12.txt")), false))
-    // });
-    // c.bench_function("day 13", |b| {
-    //     b.iter(|| day13::run(black_box(include_str!("../input/real/13.txt")), false))
-    // });
-    // c.bench_function("day 14", |b| {
-    //     b.iter(|| day14::run(black_box(include_str!("../input/real/14.txt")), false))
-    // });
-    // c.bench_function("day 15", |b| {
-    //     b.iter(|| day15::run(black_box(include_str!("../input/real/15.txt")), false))
-    // });
-    c.bench_function("day 21", |b| {
-        b.iter(|| day21::run(black_box(include_str!("../input/real/21.txt")), false))
-    });
+    let days = get_days();
+
+    // slow days are only benchmarked when they're asked for
+    let (runnables, skip_slow) = match std::env::var("DAYS") {
+        Ok(selection) => (Runnable::load_all(selection.split_whitespace()), false),
+        Err(_) => (Ok(vec![Runnable::All]), true),
+    };
+    let runnables = runnables.expect("failed to parse DAYS");
+
+    let kinds: &[bool] = match std::env::var("BENCH_INPUTS").as_deref() {
+        Ok("real") => &[false],
+        Ok("test") => &[true],
+        Ok("both") | Err(_) => &[false, true],
+        Ok(other) => panic!("unknown BENCH_INPUTS {other:?}, expected real, test or both"),
+    };
+
+    let source = InputSource::default();
+
+    for runnable in runnables {
+        let selected = runnable
+            .select(&days)
+            .expect("DAYS selected a day that doesn't exist");
+        for (id, parts) in selected {
+            let entry = &days[&id];
+            if skip_slow && entry.slow {
+                continue;
+            }
+            let mut group = c.benchmark_group(format!("{} day {:02}", id.year, id.day));
+
+            for &is_test in kinds {
+                let name = if is_test { "test" } else { "real" };
+                let input = match source.load(id, entry, is_test) {
+                    Ok(input) => input,
+                    Err(err) => {
+                        eprintln!("day {id}: skipping its {name} input: {err:#}");
+                        continue;
+                    }
+                };
+                let (input, _) = normalise(&input);
+                let params = DayParams::defaults(entry.params, is_test);
+                group.bench_function(name, |b| {
+                    b.iter(|| (entry.f)(black_box(&input), &params, parts))
+                });
+            }
+
+            group.finish();
+        }
+    }
 }
 
 criterion_group!(benches, bench_days);
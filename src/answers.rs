@@ -0,0 +1,233 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context};
+use toml::{Table, Value};
+
+use crate::{Answers, DayResult, Parts};
+
+/// Known answers for every day, stored as `<dir>/real.toml` or `<dir>/test.toml`:
+///
+/// ```toml
+/// [day01]
+/// part1 = 69528
+/// part2 = 206152
+/// ```
+///
+/// Answers are compared by their display form, so numbers may be written either as integers or
+/// strings, and multi-line answers like day 10's as multi-line strings.
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct AnswerSheet {
+    days: BTreeMap<u32, [Option<String>; 2]>,
+}
+
+/// The outcome of comparing one part against the answer sheet.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: String,
+        actual: Option<String>,
+    },
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail {
+                expected,
+                actual: Some(actual),
+            } => write!(f, "FAIL (expected {expected:?}, got {actual:?})"),
+            Verdict::Fail {
+                expected,
+                actual: None,
+            } => write!(f, "FAIL (expected {expected:?}, got no answer)"),
+            Verdict::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+impl AnswerSheet {
+    /// Where the answers for `year` are kept, like `answers/2022/real.toml`.
+    pub fn path(dir: &Path, year: u32, is_test: bool) -> PathBuf {
+        dir.join(year.to_string())
+            .join(if is_test { "test.toml" } else { "real.toml" })
+    }
+
+    /// Loads the answer sheet at `path`. A missing file is an empty sheet.
+    pub fn load(path: &Path) -> anyhow::Result<AnswerSheet> {
+        match std::fs::read_to_string(path) {
+            Ok(contents) => AnswerSheet::parse(&contents)
+                .with_context(|| format!("failed to parse {}", path.display())),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(AnswerSheet::default()),
+            Err(err) => Err(err).with_context(|| format!("failed to read {}", path.display())),
+        }
+    }
+
+    pub fn parse(contents: &str) -> anyhow::Result<AnswerSheet> {
+        let table: Table = contents.parse()?;
+        let mut days = BTreeMap::new();
+        for (key, value) in table {
+            let Some(day) = key.strip_prefix("day").and_then(|day| day.parse().ok()) else {
+                bail!("expected a table named like day01, found {key:?}");
+            };
+            let Value::Table(parts) = value else {
+                bail!("expected {key} to be a table");
+            };
+
+            let mut answers = [None, None];
+            for (part, value) in parts {
+                let slot = match part.as_str() {
+                    "part1" => &mut answers[0],
+                    "part2" => &mut answers[1],
+                    _ => bail!("expected part1 or part2 in {key}, found {part:?}"),
+                };
+                *slot = Some(match value {
+                    Value::String(s) => s,
+                    Value::Integer(i) => i.to_string(),
+                    other => {
+                        bail!("expected {key}.{part} to be a string or integer, found {other}")
+                    }
+                });
+            }
+            days.insert(day, answers);
+        }
+        Ok(AnswerSheet { days })
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .with_context(|| format!("failed to create {}", dir.display()))?;
+        }
+        std::fs::write(path, self.to_string())
+            .with_context(|| format!("failed to write {}", path.display()))
+    }
+
+    /// Compares each selected part of `result` with the known answer for `day`.
+    pub fn check(&self, day: u32, parts: Parts, result: &DayResult) -> Vec<(u8, Verdict)> {
+        let known = self.days.get(&day);
+        [
+            (1, parts.has_part1(), &result.part1),
+            (2, parts.has_part2(), &result.part2),
+        ]
+        .into_iter()
+        .filter(|&(_, selected, _)| selected)
+        .map(|(part, _, actual)| {
+            let expected = known.and_then(|answers| answers[part as usize - 1].as_ref());
+            let actual = actual.as_ref().map(Answers::to_string);
+            let verdict = match (expected, actual) {
+                (None, _) => Verdict::Unknown,
+                (Some(expected), Some(actual)) if *expected == actual => Verdict::Pass,
+                (Some(expected), actual) => Verdict::Fail {
+                    expected: expected.clone(),
+                    actual,
+                },
+            };
+            (part, verdict)
+        })
+        .collect()
+    }
+
+    /// Stores every answer in `result`, replacing anything already known for those parts.
+    pub fn record(&mut self, day: u32, result: &DayResult) {
+        let answers = self.days.entry(day).or_default();
+        for (slot, answer) in answers.iter_mut().zip([&result.part1, &result.part2]) {
+            if let Some(answer) = answer {
+                *slot = Some(answer.to_string());
+            }
+        }
+    }
+}
+
+impl Display for AnswerSheet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut table = Table::new();
+        for (day, answers) in &self.days {
+            let mut parts = Table::new();
+            for (name, answer) in ["part1", "part2"].into_iter().zip(answers) {
+                if let Some(answer) = answer {
+                    let value = match answer.parse::<i64>() {
+                        Ok(i) if i.to_string() == *answer => Value::Integer(i),
+                        _ => Value::String(answer.clone()),
+                    };
+                    parts.insert(name.to_string(), value);
+                }
+            }
+            table.insert(format!("day{day:02}"), Value::Table(parts));
+        }
+        write!(f, "{table}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AnswerSheet, Verdict};
+    use crate::{DayResult, Parts};
+
+    #[test]
+    fn recorded_answers_round_trip() {
+        let mut sheet = AnswerSheet::default();
+        sheet.record(
+            1,
+            &DayResult {
+                part1: Some(24_000_u64.into()),
+                part2: Some(45_000_u64.into()),
+            },
+        );
+        sheet.record(
+            10,
+            &DayResult {
+                part1: Some(13_140_i64.into()),
+                part2: Some("##  ##\n#  # \"x\"\n".into()),
+            },
+        );
+        sheet.record(
+            17,
+            &DayResult {
+                part1: Some(3_068_usize.into()),
+                part2: None,
+            },
+        );
+
+        let saved = sheet.to_string();
+        assert!(saved.starts_with("[day01]\npart1 = 24000\n"));
+        assert_eq!(AnswerSheet::parse(&saved).unwrap(), sheet);
+    }
+
+    #[test]
+    fn answers_are_checked_per_part() {
+        let sheet = AnswerSheet::parse("[day02]\npart1 = 15\npart2 = \"12\"\n").unwrap();
+        let result = DayResult {
+            part1: Some(15_u32.into()),
+            part2: Some(13_u32.into()),
+        };
+
+        assert_eq!(
+            sheet.check(2, Parts::Both, &result),
+            vec![
+                (1, Verdict::Pass),
+                (
+                    2,
+                    Verdict::Fail {
+                        expected: "12".to_string(),
+                        actual: Some("13".to_string())
+                    }
+                )
+            ]
+        );
+        assert_eq!(
+            sheet.check(2, Parts::Part1, &result),
+            vec![(1, Verdict::Pass)]
+        );
+        assert_eq!(
+            sheet.check(3, Parts::Both, &result),
+            vec![(1, Verdict::Unknown), (2, Verdict::Unknown)]
+        );
+        assert!(AnswerSheet::parse("[day02]\npart3 = 1\n").is_err());
+    }
+}
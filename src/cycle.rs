@@ -0,0 +1,177 @@
+//! Finding where a long simulation starts repeating itself, so it needn't be run to the end.
+//!
+//! The simulation is stepped until a key taken from its state comes round again. From then on
+//! every step is assumed to repeat the one a cycle before, so a metric that grows by the same
+//! amount each time round, like a height or a count, can be worked out for any step from the ones
+//! already seen.
+//!
+//! Every step taken is kept in the history, so the search is given a limit small enough to
+//! simulate in full, and steps past it can only be worked out if a cycle turned up before then.
+
+use std::collections::hash_map::Entry;
+use std::hash::Hash;
+use std::ops::Sub;
+
+use fxhash::FxHashMap;
+use num::{CheckedAdd, CheckedMul};
+use thiserror::Error;
+
+/// Where a run of states starts repeating itself.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Cycle {
+    /// The first step whose state is seen again.
+    pub start: usize,
+    /// How many steps it takes to come round again.
+    pub period: usize,
+}
+
+/// A simulation's metric after each step it took, and the cycle it fell into if it found one.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Run<M> {
+    /// The metric before any steps, then after each one.
+    pub history: Vec<M>,
+    pub cycle: Option<Cycle>,
+}
+
+/// Steps `state` until `key` gives something it's given before, or `limit` steps have been taken.
+/// The key has to tell apart any two states whose futures differ, though it can leave out
+/// whatever `metric` is tracking. The metric after every step is kept, so `limit` bounds the
+/// memory used as well as the time, and shouldn't be the step that's actually wanted when that's
+/// too far off to simulate.
+pub fn find_cycle<S, K, M>(
+    state: &mut S,
+    limit: usize,
+    mut step: impl FnMut(&mut S),
+    mut key: impl FnMut(&S) -> K,
+    mut metric: impl FnMut(&S) -> M,
+) -> Run<M>
+where
+    K: Eq + Hash,
+{
+    let mut seen = FxHashMap::default();
+    let mut history = vec![metric(state)];
+    seen.insert(key(state), 0);
+
+    for steps in 1..=limit {
+        step(state);
+        history.push(metric(state));
+        match seen.entry(key(state)) {
+            Entry::Occupied(entry) => {
+                let start = *entry.get();
+                let cycle = Cycle {
+                    start,
+                    period: steps - start,
+                };
+                return Run {
+                    history,
+                    cycle: Some(cycle),
+                };
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(steps);
+            }
+        }
+    }
+
+    Run {
+        history,
+        cycle: None,
+    }
+}
+
+impl<M> Run<M>
+where
+    M: Copy + CheckedAdd + Sub<Output = M> + CheckedMul + TryFrom<usize>,
+{
+    /// The metric after `steps` steps, if they were run or the cycle says what it'd be.
+    pub fn at(&self, steps: usize) -> Result<M, CycleError> {
+        if let Some(&seen) = self.history.get(steps) {
+            return Ok(seen);
+        }
+
+        let Cycle { start, period } = self.cycle.ok_or(CycleError::NotFound {
+            steps,
+            searched: self.history.len() - 1,
+        })?;
+        let (laps, rest) = ((steps - start) / period, (steps - start) % period);
+        let per_lap = self.history[start + period] - self.history[start];
+        M::try_from(laps)
+            .ok()
+            .and_then(|laps| per_lap.checked_mul(&laps))
+            .and_then(|grown| self.history[start + rest].checked_add(&grown))
+            .ok_or(CycleError::Overflow { steps })
+    }
+}
+
+#[derive(Debug, Error, Eq, PartialEq)]
+pub enum CycleError {
+    #[error(
+        "No cycle turned up in the first {searched} steps, so step {steps} can't be worked out"
+    )]
+    NotFound { steps: usize, searched: usize },
+    #[error("The metric after {steps} steps is too big to work out")]
+    Overflow { steps: usize },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_cycle, Cycle, CycleError};
+
+    // counts up to 10, then goes back to 6 and counts up again forever
+    fn step(state: &mut (u64, i64)) {
+        state.0 = if state.0 == 10 { 6 } else { state.0 + 1 };
+        state.1 += state.0 as i64;
+    }
+
+    #[test]
+    fn cycles_are_found_and_followed() {
+        let mut state = (0, 0);
+        let run = find_cycle(&mut state, 1_000, step, |s| s.0, |s| s.1);
+        assert_eq!(
+            run.cycle,
+            Some(Cycle {
+                start: 6,
+                period: 5
+            })
+        );
+        assert_eq!(run.history.len(), 12);
+
+        let mut brute = (0, 0);
+        for steps in 0..100 {
+            assert_eq!(run.at(steps), Ok(brute.1), "after {steps} steps");
+            step(&mut brute);
+        }
+        assert_eq!(run.at(1_000_000_000_000), Ok(8_000_000_000_000 - 25));
+        assert_eq!(
+            run.at(usize::MAX),
+            Err(CycleError::Overflow { steps: usize::MAX })
+        );
+    }
+
+    #[test]
+    fn runs_without_a_cycle_stop_at_the_limit() {
+        let mut state = 0_i64;
+        let run = find_cycle(&mut state, 5, |s| *s += 1, |s| *s, |s| *s * 2);
+        assert_eq!(run.cycle, None);
+        assert_eq!(run.at(5), Ok(10));
+        assert_eq!(
+            run.at(6),
+            Err(CycleError::NotFound {
+                steps: 6,
+                searched: 5
+            })
+        );
+
+        // giving up on a cycle doesn't mean simulating all the way to the step that's wanted
+        let mut state = 0_i64;
+        let run = find_cycle(&mut state, 1_000, |s| *s += 1, |s| *s, |s| *s);
+        assert_eq!(state, 1_000);
+        assert_eq!(
+            run.at(1_000_000_000_000),
+            Err(CycleError::NotFound {
+                steps: 1_000_000_000_000,
+                searched: 1_000
+            })
+        );
+    }
+}
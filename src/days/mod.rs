@@ -1,39 +1,177 @@
+use crate::input::InputError;
+use crate::Registry;
 use num::Num;
-use std::ops::{Add, Mul, Sub};
-
-pub mod day01;
-pub mod day02;
-pub mod day03;
-pub mod day04;
-pub mod day05;
-pub mod day06;
-pub mod day07;
-pub mod day08;
-pub mod day09;
-pub mod day10;
-pub mod day11;
-pub mod day12;
-pub mod day13;
-pub mod day14;
-pub mod day15;
-pub mod day16;
-pub mod day17;
-pub mod day18;
-pub mod day19;
-pub mod day20;
-pub mod day21;
-pub mod day22;
-pub mod day23;
-pub mod day24;
+
+#[cfg(feature = "embedded-inputs")]
+macro_rules! embedded {
+    ($path:expr) => {
+        Some(include_str!($path))
+    };
+}
+
+#[cfg(not(feature = "embedded-inputs"))]
+macro_rules! embedded {
+    ($path:expr) => {
+        None
+    };
+}
+
+// adapts a day that still solves both parts in a single `run`
+macro_rules! whole {
+    ($run:path) => {
+        |input, params, parts| crate::run_whole($run, input, params, parts)
+    };
+}
+
+/// Declares the modules of a year's days and lists them in a `get_days` function, along with
+/// what's known about each day. `partN` are the answers for the example input. Slow days are
+/// skipped by the example tests, by `--skip-slow`, and by the benchmarks unless they're asked for.
+///
+/// ```ignore
+/// register_days! {
+///     year: 2022,
+///     01 => day01 {
+///         title: "Calorie Counting",
+///         solver: crate::run_phases::<day01::Day01>,
+///         params: day01::PARAMS,
+///         part1: "24000",
+///         part2: "45000",
+///         slow: false,
+///     },
+/// }
+/// ```
+///
+/// Only `title` and `solver` are required. Days are written with two digits, as their inputs are
+/// named.
+macro_rules! register_days {
+    (
+        year: $year:literal,
+        $(
+            $day:literal => $module:ident {
+                title: $title:literal,
+                solver: $solver:expr,
+                $(params: $params:expr,)?
+                $(part1: $part1:expr,)?
+                $(part2: $part2:expr,)?
+                $(slow: $slow:literal,)?
+            },
+        )*
+    ) => {
+        $(
+            pub mod $module;
+        )*
+
+        #[doc = concat!("Every solved day of ", $year, ".")]
+        #[allow(clippy::zero_prefixed_literal)]
+        pub fn get_days() -> Vec<(crate::DayId, crate::DayEntry)> {
+            vec![
+                $(
+                    (
+                        crate::DayId::new($year, $day),
+                        crate::DayEntry {
+                            title: $title,
+                            f: $solver,
+                            params: register_days!(@or [$($params)?] &[]),
+                            real: embedded!(concat!(
+                                "../../../input/", $year, "/real/", stringify!($day), ".txt"
+                            )),
+                            test: embedded!(concat!(
+                                "../../../input/", $year, "/test/", stringify!($day), ".txt"
+                            )),
+                            example: [
+                                register_days!(@option [$($part1)?]),
+                                register_days!(@option [$($part2)?]),
+                            ],
+                            slow: register_days!(@or [$($slow)?] false),
+                        },
+                    ),
+                )*
+            ]
+        }
+    };
+    (@or [$value:expr] $default:expr) => {
+        $value
+    };
+    (@or [] $default:expr) => {
+        $default
+    };
+    (@option [$value:expr]) => {
+        Some($value)
+    };
+    (@option []) => {
+        None
+    };
+}
+
+pub mod y2022;
 
 #[inline(always)]
-pub fn byte_slice_to_int<T: Num + From<u8> + Add + Sub + Mul + Copy>(slice: &[u8]) -> T {
+/// Reads `digits` as a number without going through `str::parse`. `digits` is a part of `input`,
+/// which an error points into if it has anything other than ASCII digits.
+pub fn parse_number<T: Num + From<u8> + Copy>(input: &str, digits: &[u8]) -> Result<T, InputError> {
+    if digits.is_empty() {
+        return Err(InputError::within(input, digits, "a number"));
+    }
+
     let mut res = T::zero();
     let ten: T = 10.into();
 
-    for &b in slice {
-        res = res * ten + b.into() - b'0'.into();
+    for (i, &b) in digits.iter().enumerate() {
+        if !b.is_ascii_digit() {
+            return Err(InputError::within(input, &digits[i..], "a digit"));
+        }
+        res = res * ten + (b - b'0').into();
     }
 
-    res
+    Ok(res)
+}
+
+/// Every day of every year that has a solution.
+pub fn get_days() -> Registry {
+    let years = [y2022::get_days()];
+    years.into_iter().flatten().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{get_days, parse_number};
+    use crate::input::{normalise, InputSource};
+    use crate::params::DayParams;
+    use crate::Parts;
+    use std::path::Path;
+
+    #[test]
+    fn days_solve_their_examples() {
+        let inputs = InputSource::Dir(Path::new(env!("CARGO_MANIFEST_DIR")).join("input"));
+        for (id, entry) in get_days().into_iter().filter(|(_, entry)| !entry.slow) {
+            let input = inputs.load(id, &entry, true).unwrap();
+            let (input, _) = normalise(&input);
+            let params = DayParams::defaults(entry.params, true);
+            let (result, _) = (entry.f)(&input, &params, Parts::Both).unwrap();
+            let answers = [result.part1, result.part2].map(|answer| answer.map(|a| a.to_string()));
+            for (part, (actual, expected)) in (1..).zip(answers.iter().zip(entry.example)) {
+                assert_eq!(actual.as_deref(), expected, "{id} part {part}");
+            }
+        }
+    }
+
+    #[test]
+    fn days_are_registered_once() {
+        let listed = super::y2022::get_days();
+        assert_eq!(get_days().len(), listed.len());
+        assert!(listed.iter().all(|(_, entry)| !entry.title.is_empty()));
+    }
+
+    #[test]
+    fn numbers_are_checked() {
+        let input = "R 12\nL 1x\nU \n";
+        assert_eq!(parse_number::<u32>(input, &input.as_bytes()[2..4]), Ok(12));
+        let err = parse_number::<u32>(input, &input.as_bytes()[7..9]).unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 4, "'x'"));
+        let err = parse_number::<u32>(input, &input.as_bytes()[12..12]).unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.found.as_str()),
+            (3, 3, "end of line")
+        );
+    }
 }
@@ -1,3 +1,5 @@
+use crate::input::InputError;
+use crate::params::DayParams;
 use crate::{DayResult, IntoDayResult};
 
 macro_rules! sort_arr {
@@ -15,15 +17,15 @@ macro_rules! sort_arr {
     };
 }
 
-pub fn run(input: &'static str, _: bool) -> anyhow::Result<DayResult> {
+pub fn run(input: &str, _: &DayParams) -> anyhow::Result<DayResult> {
     let mut calorie_sums = [0; 3];
 
-    let input = input.as_bytes();
+    let bytes = input.as_bytes();
     let mut curr_int: u32 = 0;
     let mut sum = 0;
     let mut last_newline = 0;
 
-    for (i, &byte) in input.iter().enumerate() {
+    for (i, &byte) in bytes.iter().enumerate() {
         match byte {
             b'\n' => {
                 if last_newline + 1 == i {
@@ -38,7 +40,7 @@ pub fn run(input: &'static str, _: bool) -> anyhow::Result<DayResult> {
             b'0'..=b'9' => {
                 curr_int = curr_int * 10 + (byte - b'0') as u32;
             }
-            _ => unreachable!(),
+            _ => return Err(InputError::at(input, i, "a digit or newline").into()),
         }
     }
 
@@ -53,11 +55,15 @@ pub fn run(input: &'static str, _: bool) -> anyhow::Result<DayResult> {
 #[cfg(test)]
 mod tests {
     use super::run;
+    use crate::params::DayParams;
     use crate::{Answers, DayResult};
 
     #[test]
     fn test_example_answers() {
-        let result = run(include_str!("../../input/test/01.txt"), false);
+        let result = run(
+            include_str!("../../../input/2022/test/01.txt"),
+            &DayParams::default(),
+        );
         assert_eq!(
             result.unwrap(),
             DayResult {
@@ -69,7 +75,10 @@ mod tests {
 
     #[test]
     fn test_answers() {
-        let result = run(include_str!("../../input/real/01.txt"), false);
+        let result = run(
+            include_str!("../../../input/2022/real/01.txt"),
+            &DayParams::default(),
+        );
         assert_eq!(
             result.unwrap(),
             DayResult {
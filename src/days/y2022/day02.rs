@@ -1,3 +1,5 @@
+use crate::input::InputError;
+use crate::params::DayParams;
 use crate::{DayResult, IntoDayResult};
 
 const LOOKUP_SCORE: [[usize; 3]; 3] = [
@@ -12,29 +14,41 @@ const LOOKUP_MOVE: [[usize; 3]; 3] = [
     [2, 6, 7], // scissors
 ];
 
-pub fn run(input: &'static str, _: bool) -> anyhow::Result<DayResult> {
+pub fn run(input: &str, _: &DayParams) -> anyhow::Result<DayResult> {
     let input = input.as_bytes();
 
     (0..input.len())
         .step_by(4)
-        .fold((0, 0), |(p1, p2), i| {
-            let opp = (input[i] - b'A') as usize;
-            let me_or_goal = (input[i + 2] - b'X') as usize;
+        .try_fold((0, 0), |(p1, p2), i| {
+            let opp = shape_at(input, i, b'A', "A, B or C")?;
+            let me_or_goal = shape_at(input, i + 2, b'X', "X, Y or Z")?;
             let p1 = p1 + LOOKUP_SCORE[me_or_goal][opp];
             let p2 = p2 + LOOKUP_MOVE[opp][me_or_goal];
-            (p1, p2)
-        })
+            Ok::<_, InputError>((p1, p2))
+        })?
         .into_result()
 }
 
+/// Reads the shape at `i`, one of the three letters from `first`.
+fn shape_at(input: &[u8], i: usize, first: u8, expected: &str) -> Result<usize, InputError> {
+    match input.get(i) {
+        Some(&b) if (first..first + 3).contains(&b) => Ok((b - first) as usize),
+        _ => Err(InputError::at(input, i, expected)),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::run;
+    use crate::params::DayParams;
     use crate::{Answers, DayResult};
 
     #[test]
     fn test_example_answers() {
-        let result = run(include_str!("../../input/test/02.txt"), false);
+        let result = run(
+            include_str!("../../../input/2022/test/02.txt"),
+            &DayParams::default(),
+        );
         assert_eq!(
             result.unwrap(),
             DayResult {
@@ -46,7 +60,10 @@ mod tests {
 
     #[test]
     fn test_answers() {
-        let result = run(include_str!("../../input/real/02.txt"), false);
+        let result = run(
+            include_str!("../../../input/2022/real/02.txt"),
+            &DayParams::default(),
+        );
         assert_eq!(
             result.unwrap(),
             DayResult {
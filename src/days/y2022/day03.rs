@@ -1,3 +1,5 @@
+use crate::input::InputError;
+use crate::params::DayParams;
 use crate::{DayResult, IntoDayResult};
 use bstr::{BStr, ByteSlice};
 
@@ -7,10 +9,17 @@ const LOOKUP: [u64; 64] = [
     20, 21, 22, 23, 24, 25, 26, 0, 0, 0, 0, 0, 0,
 ];
 
-pub fn run(input: &'static str, _: bool) -> anyhow::Result<DayResult> {
-    let (part1, part2, _) = BStr::new(input).lines().enumerate().fold(
+pub fn run(input: &str, _: &DayParams) -> anyhow::Result<DayResult> {
+    let (part1, part2, _) = BStr::new(input).lines().enumerate().try_fold(
         (0_u64, 0_u64, u64::MAX),
         |(mut part1, mut part2, mut group), (i, line)| {
+            if let Some(bad) = line.iter().position(|b| !b.is_ascii_alphabetic()) {
+                return Err(InputError::within(input, &line[bad..], "a letter"));
+            }
+            if line.len() % 2 != 0 {
+                let end = &line[line.len()..];
+                return Err(InputError::within(input, end, "another item, to fill both halves"));
+            }
             let (a, b) = line.split_at(line.len() / 2);
             let (a, b) = a
                 .iter()
@@ -19,27 +28,38 @@ pub fn run(input: &'static str, _: bool) -> anyhow::Result<DayResult> {
                     (acc_a | (1 << (a - b'A')), acc_b | 1 << (b - b'A'))
                 });
 
+            if a & b == 0 {
+                return Err(InputError::within(input, line, "an item in both halves"));
+            }
             part1 += LOOKUP[(a & b).trailing_zeros() as usize];
 
             group &= a | b;
             if i % 3 == 2 {
+                if group == 0 {
+                    return Err(InputError::within(input, line, "an item the group all carry"));
+                }
                 part2 += LOOKUP[group.trailing_zeros() as usize];
                 group = u64::MAX;
             }
-            (part1, part2, group)
+            Ok((part1, part2, group))
         },
-    );
+    )?;
     (part1, part2).into_result()
 }
 
 #[cfg(test)]
 mod tests {
     use super::run;
+    use crate::input::InputError;
+    use crate::params::DayParams;
     use crate::{Answers, DayResult};
 
     #[test]
     fn test_example_answers() {
-        let result = run(include_str!("../../input/test/03.txt"), false);
+        let result = run(
+            include_str!("../../../input/2022/test/03.txt"),
+            &DayParams::default(),
+        );
         assert_eq!(
             result.unwrap(),
             DayResult {
@@ -51,7 +71,10 @@ mod tests {
 
     #[test]
     fn test_answers() {
-        let result = run(include_str!("../../input/real/03.txt"), false);
+        let result = run(
+            include_str!("../../../input/2022/real/03.txt"),
+            &DayParams::default(),
+        );
         assert_eq!(
             result.unwrap(),
             DayResult {
@@ -60,4 +83,13 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_bad_input() {
+        for (input, line, column) in [("x\n", 1, 2), ("ab\n", 1, 1), ("aa\nbb\ncc\n", 3, 1)] {
+            let err = run(input, &DayParams::default()).unwrap_err();
+            let err = err.downcast::<InputError>().unwrap();
+            assert_eq!((err.line, err.column), (line, column), "{input:?}");
+        }
+    }
 }
@@ -1,13 +1,15 @@
+use crate::input::InputError;
+use crate::params::DayParams;
 use crate::{DayResult, IntoDayResult};
 
-pub fn run(input: &'static str, _: bool) -> anyhow::Result<DayResult> {
+pub fn run(input: &str, _: &DayParams) -> anyhow::Result<DayResult> {
     let mut part1 = 0;
     let mut part2 = 0;
 
     let mut nums = [0_usize; 4];
     let mut curr = 0_usize;
 
-    for &b in input.as_bytes() {
+    for (i, &b) in input.as_bytes().iter().enumerate() {
         match b {
             b'\n' => {
                 let [a, b, c, d] = nums;
@@ -20,14 +22,14 @@ pub fn run(input: &'static str, _: bool) -> anyhow::Result<DayResult> {
                 nums = [0; 4];
                 curr = 0;
             }
-            b'-' | b',' => {
+            b'-' | b',' if curr < 3 => {
                 curr += 1;
             }
             b'0'..=b'9' => {
                 nums[curr] *= 10;
                 nums[curr] += b as usize;
             }
-            _ => unreachable!(),
+            _ => return Err(InputError::at(input, i, "a pair of ranges like 2-4,6-8").into()),
         }
     }
 
@@ -37,11 +39,16 @@ pub fn run(input: &'static str, _: bool) -> anyhow::Result<DayResult> {
 #[cfg(test)]
 mod tests {
     use super::run;
+    use crate::input::InputError;
+    use crate::params::DayParams;
     use crate::DayResult;
 
     #[test]
     fn test_example_answers() {
-        let result = run(include_str!("../../input/test/04.txt"), false);
+        let result = run(
+            include_str!("../../../input/2022/test/04.txt"),
+            &DayParams::default(),
+        );
         assert_eq!(
             result.unwrap(),
             DayResult {
@@ -53,7 +60,10 @@ mod tests {
 
     #[test]
     fn test_answers() {
-        let result = run(include_str!("../../input/real/04.txt"), false);
+        let result = run(
+            include_str!("../../../input/2022/real/04.txt"),
+            &DayParams::default(),
+        );
         assert_eq!(
             result.unwrap(),
             DayResult {
@@ -62,4 +72,11 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_bad_input() {
+        let err = run("2-4,6-8\n2-3;4-5\n", &DayParams::default()).unwrap_err();
+        let err = err.downcast::<InputError>().unwrap();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 4, "';'"));
+    }
 }
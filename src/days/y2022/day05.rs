@@ -0,0 +1,127 @@
+use crate::input::InputError;
+use crate::params::DayParams;
+use crate::{DayResult, IntoDayResult};
+use anyhow::Context;
+use bstr::{BStr, ByteSlice};
+use nom::bytes::complete::tag;
+use nom::sequence::{preceded, tuple};
+use nom::IResult;
+
+pub fn run(input: &str, _: &DayParams) -> anyhow::Result<DayResult> {
+    let mut lines_iter = BStr::new(input).lines();
+
+    let mut cranes_part1: Vec<Vec<char>> = vec![];
+
+    for line in &mut lines_iter {
+        match line.get(1) {
+            Some(&f) if f == b' ' || f.is_ascii_uppercase() => {}
+            _ => break,
+        }
+        // rows can be cut short once trailing spaces are trimmed, so stacks are only ever added
+        let stacks = (1..line.len()).step_by(4).count();
+        if stacks > cranes_part1.len() {
+            cranes_part1.resize_with(stacks, Vec::new);
+        }
+        for (col, &c) in line.iter().skip(1).step_by(4).enumerate() {
+            if c.is_ascii_alphabetic() {
+                cranes_part1[col].push(c as char);
+            }
+        }
+    }
+
+    for crane in &mut cranes_part1 {
+        crane.reverse();
+    }
+
+    let mut cranes_part2 = cranes_part1.clone();
+
+    for command in lines_iter.skip(1) {
+        let (count, start, dest) = parse_line(command)
+            .map_err(|e| InputError::nom(input.as_bytes(), e, "a move like move 1 from 2 to 1"))?
+            .1;
+        let stack_count = cranes_part1.len();
+        let bad_move = || {
+            let expected = format!("a move between two of stacks 1 to {stack_count}");
+            InputError {
+                found: format!("{:?}", command.as_bstr()),
+                ..InputError::within(input, command, expected)
+            }
+        };
+        let stacks = [start as usize, dest as usize].map(|stack| stack.wrapping_sub(1));
+        let count = count as usize;
+
+        let [from, to] = cranes_part1
+            .get_disjoint_mut(stacks)
+            .map_err(|_| bad_move())?;
+        let Some(left) = from.len().checked_sub(count) else {
+            return Err(bad_move().into());
+        };
+        to.extend(from[left..].iter().rev());
+        from.truncate(left);
+
+        let [from, to] = cranes_part2
+            .get_disjoint_mut(stacks)
+            .map_err(|_| bad_move())?;
+        to.extend_from_slice(&from[left..]);
+        from.truncate(left);
+    }
+
+    let part1 = cranes_part1
+        .iter()
+        .map(|c| c.last())
+        .collect::<Option<String>>()
+        .context("a crane had no contents")?;
+
+    let part2 = cranes_part2
+        .iter()
+        .map(|c| c.last())
+        .collect::<Option<String>>()
+        .context("a crane had no contents")?;
+
+    (part1, part2).into_result()
+}
+
+fn parse_line(line: &[u8]) -> IResult<&[u8], (u32, u32, u32)> {
+    tuple((
+        preceded(tag("move "), nom::character::complete::u32),
+        preceded(tag(" from "), nom::character::complete::u32),
+        preceded(tag(" to "), nom::character::complete::u32),
+    ))(line)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::run;
+    use crate::params::DayParams;
+    use crate::DayResult;
+
+    #[test]
+    fn test_example_answers() {
+        let result = run(
+            include_str!("../../../input/2022/test/05.txt"),
+            &DayParams::default(),
+        );
+        assert_eq!(
+            result.unwrap(),
+            DayResult {
+                part1: Some("CMZ".into()),
+                part2: Some("MCD".into()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_answers() {
+        let result = run(
+            include_str!("../../../input/2022/real/05.txt"),
+            &DayParams::default(),
+        );
+        assert_eq!(
+            result.unwrap(),
+            DayResult {
+                part1: Some("CNSZFDVLJ".into()),
+                part2: Some("QNDWLMGNS".into()),
+            }
+        );
+    }
+}
@@ -1,8 +1,13 @@
+use crate::input::InputError;
+use crate::params::DayParams;
 use crate::{DayResult, IntoDayResult};
 use anyhow::Context;
 
-pub fn run(input: &'static str, _: bool) -> anyhow::Result<DayResult> {
-    let input = input.as_bytes();
+pub fn run(input: &str, _: &DayParams) -> anyhow::Result<DayResult> {
+    let input = input.trim_end().as_bytes();
+    if let Some(bad) = input.iter().position(|b| !b.is_ascii_lowercase()) {
+        return Err(InputError::at(input, bad, "a lowercase letter").into());
+    }
 
     let part1 = find_unique_len(4, input).context("exp an an answer")?;
     let part2 = find_unique_len(14, input).context("exp an an answer")?;
@@ -30,11 +35,15 @@ fn find_unique_len(len: usize, src: &[u8]) -> Option<usize> {
 #[cfg(test)]
 mod tests {
     use super::run;
+    use crate::params::DayParams;
     use crate::DayResult;
 
     #[test]
     fn test_example_answers() {
-        let result = run(include_str!("../../input/test/06.txt"), false);
+        let result = run(
+            include_str!("../../../input/2022/test/06.txt"),
+            &DayParams::default(),
+        );
         assert_eq!(
             result.unwrap(),
             DayResult {
@@ -46,7 +55,10 @@ mod tests {
 
     #[test]
     fn test_answers() {
-        let result = run(include_str!("../../input/real/06.txt"), false);
+        let result = run(
+            include_str!("../../../input/2022/real/06.txt"),
+            &DayParams::default(),
+        );
         assert_eq!(
             result.unwrap(),
             DayResult {
@@ -1,10 +1,11 @@
-use crate::days::byte_slice_to_int;
+use crate::days::parse_number;
+use crate::input::InputError;
+use crate::params::DayParams;
 use crate::{DayResult, IntoDayResult};
 use bstr::{BStr, ByteSlice};
-use nom::Slice;
 
-pub fn run(input: &'static str, _: bool) -> anyhow::Result<DayResult> {
-    let mut fs = load_filesystem(input);
+pub fn run(input: &str, _: &DayParams) -> anyhow::Result<DayResult> {
+    let mut fs = load_filesystem(input)?;
     let (sum, part1) = find_dir_sizes(&mut fs);
     let part2 = find_dir_to_delete(&fs, sum);
     (part1, part2).into_result()
@@ -53,52 +54,58 @@ fn find_smallest_above_size(dir: &Entry, tot_size: usize, best: usize) -> usize
     best
 }
 
-fn load_filesystem(input: &str) -> Entry {
+fn load_filesystem(input: &str) -> Result<Entry, InputError> {
     let mut lines = BStr::new(input).lines();
 
-    load_inner(&mut lines)
+    load_inner(input, &mut lines)
 }
 
-fn load_inner<'a, I: Iterator<Item = &'a [u8]>>(lines: &mut I) -> Entry {
+fn load_inner<'a, I: Iterator<Item = &'a [u8]>>(
+    input: &str,
+    lines: &mut I,
+) -> Result<Entry, InputError> {
     let mut cur_dir = Entry::default();
 
     let mut is_ls = false;
     while let Some(line) = lines.next() {
-        if is_ls && is_ls_output(line, &mut cur_dir) {
+        if is_ls && is_ls_output(input, line, &mut cur_dir)? {
             continue;
         }
 
-        if line.starts_with(b"$ cd") {
-            if line.slice(5..) == b".." {
-                return cur_dir;
+        if let Some(dir) = line.strip_prefix(b"$ cd ") {
+            if dir == b".." {
+                return Ok(cur_dir);
             }
 
-            cur_dir.contents.push(load_inner(lines));
-        } else if line.starts_with(b"$ ls") {
+            cur_dir.contents.push(load_inner(input, lines)?);
+        } else if line == b"$ ls" {
             is_ls = true;
+        } else {
+            return Err(InputError::within(input, line, "a cd or ls command"));
         }
     }
 
-    cur_dir
+    Ok(cur_dir)
 }
 
 #[inline(always)]
-fn is_ls_output(line: &[u8], dir: &mut Entry) -> bool {
+fn is_ls_output(input: &str, line: &[u8], dir: &mut Entry) -> Result<bool, InputError> {
     if line.starts_with(b"$") {
-        return false;
+        return Ok(false);
     }
 
-    if line.starts_with(b"dir") {
-        return true;
+    if line.starts_with(b"dir ") {
+        return Ok(true);
     }
 
-    let Some(a) = line.split(|&b| b == b' ').next() else {
-        return false;
+    let Some(space) = line.iter().position(|&b| b == b' ') else {
+        let expected = "a directory or a file like 14848514 b.txt";
+        return Err(InputError::within(input, line, expected));
     };
 
-    dir.size += byte_slice_to_int::<usize>(a);
+    dir.size += parse_number::<usize>(input, &line[..space])?;
 
-    true
+    Ok(true)
 }
 
 #[derive(Debug, Default)]
@@ -110,11 +117,15 @@ struct Entry {
 #[cfg(test)]
 mod tests {
     use super::run;
+    use crate::params::DayParams;
     use crate::DayResult;
 
     #[test]
     fn test_example_answers() {
-        let result = run(include_str!("../../input/test/07.txt"), false);
+        let result = run(
+            include_str!("../../../input/2022/test/07.txt"),
+            &DayParams::default(),
+        );
         assert_eq!(
             result.unwrap(),
             DayResult {
@@ -126,7 +137,10 @@ mod tests {
 
     #[test]
     fn test_answers() {
-        let result = run(include_str!("../../input/real/07.txt"), false);
+        let result = run(
+            include_str!("../../../input/2022/real/07.txt"),
+            &DayParams::default(),
+        );
         assert_eq!(
             result.unwrap(),
             DayResult {
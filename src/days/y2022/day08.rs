@@ -0,0 +1,148 @@
+use crate::geom::Point2;
+use crate::grid::Grid;
+use crate::params::DayParams;
+use crate::{DayResult, IntoDayResult};
+
+pub fn run(input: &str, _: &DayParams) -> anyhow::Result<DayResult> {
+    let trees = Grid::parse(input, "a tree's height", |b| b.is_ascii_digit().then_some(b))?;
+    let (width, height) = (trees.width(), trees.height());
+
+    let mut visible = Grid::new(width, height, false);
+
+    for (y, line) in trees.rows().enumerate() {
+        let mut smallest: u8 = 0;
+        for (x, &tree) in line.iter().enumerate() {
+            if tree > smallest {
+                visible[Point2::new(x, y)] = true;
+                smallest = tree;
+            }
+        }
+
+        let mut smallest: u8 = 0;
+        for (x, &tree) in line.iter().enumerate().rev() {
+            if tree > smallest {
+                visible[Point2::new(x, y)] = true;
+                smallest = tree;
+            }
+        }
+    }
+
+    // columns are read often enough that it's worth having them as rows
+    let columns = trees.transpose();
+
+    for (x, column) in columns.rows().enumerate() {
+        let mut smallest: u8 = 0;
+        for (y, &tree) in column.iter().enumerate() {
+            if tree > smallest {
+                visible[Point2::new(x, y)] = true;
+                smallest = tree;
+            }
+        }
+
+        let mut smallest: u8 = 0;
+        for (y, &tree) in column.iter().enumerate().rev() {
+            if tree > smallest {
+                visible[Point2::new(x, y)] = true;
+                smallest = tree;
+            }
+        }
+    }
+
+    let part1 = visible.rows().flatten().filter(|&&b| b).count();
+
+    let mut part2: usize = 0;
+
+    for (y, line) in trees.rows().enumerate().skip(1) {
+        for (x, &home) in line.iter().enumerate().skip(1) {
+            let column = columns.row(x);
+            let mut a = 0;
+            for &t in column[..y].iter().rev() {
+                a += 1;
+
+                if t >= home {
+                    break;
+                }
+            }
+
+            if a * (height - y) * (width - x) * x < part2 {
+                continue;
+            }
+
+            let mut b = 0;
+            for &t in column[y + 1..].iter() {
+                b += 1;
+
+                if t >= home {
+                    break;
+                }
+            }
+
+            if a * b * (width - x) * x < part2 {
+                continue;
+            }
+
+            let mut c = 0;
+            for &t in line[..x].iter().rev() {
+                c += 1;
+
+                if t >= home {
+                    break;
+                }
+            }
+
+            if a * b * c * (width - x) < part2 {
+                continue;
+            }
+
+            let mut d = 0;
+            for &t in line[x + 1..].iter() {
+                d += 1;
+
+                if t >= home {
+                    break;
+                }
+            }
+
+            part2 = std::cmp::max(part2, a * b * c * d);
+        }
+    }
+
+    (part1, part2).into_result()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::run;
+    use crate::params::DayParams;
+    use crate::DayResult;
+
+    #[test]
+    fn test_example_answers() {
+        let result = run(
+            include_str!("../../../input/2022/test/08.txt"),
+            &DayParams::default(),
+        );
+        assert_eq!(
+            result.unwrap(),
+            DayResult {
+                part1: Some(21.into()),
+                part2: Some(8.into()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_answers() {
+        let result = run(
+            include_str!("../../../input/2022/real/08.txt"),
+            &DayParams::default(),
+        );
+        assert_eq!(
+            result.unwrap(),
+            DayResult {
+                part1: Some(1_669.into()),
+                part2: Some(331_344.into()),
+            }
+        );
+    }
+}
@@ -0,0 +1,108 @@
+use crate::days::parse_number;
+use crate::geom::{Point2, Vec2};
+use crate::infinite::InfiniteGrid2;
+use crate::input::InputError;
+use crate::params::DayParams;
+use crate::{DayResult, IntoDayResult};
+use bstr::{BStr, ByteSlice};
+
+pub fn run(input: &str, _: &DayParams) -> anyhow::Result<DayResult> {
+    // every tail starts out having visited where the rope starts
+    let mut part1 = 1;
+    let mut part2 = 1;
+    let mut seen_1 = InfiniteGrid2::new(false);
+    let mut seen_2 = InfiniteGrid2::new(false);
+    seen_1.set(Point2::<i64>::default(), true);
+    seen_2.set(Point2::<i64>::default(), true);
+    let mut ropes = [Point2::<i64>::default(); 10];
+    for line in BStr::new(input).lines() {
+        // y grows upwards here, so these aren't `geom::Direction`s
+        let step = match line.first() {
+            Some(b'L') => Vec2::new(-1, 0),
+            Some(b'R') => Vec2::new(1, 0),
+            Some(b'U') => Vec2::new(0, 1),
+            Some(b'D') => Vec2::new(0, -1),
+            _ => return Err(InputError::within(input, line, "L, R, U or D").into()),
+        };
+        let dist = match &line[1..] {
+            [b' ', dist @ ..] => parse_number::<i64>(input, dist)?,
+            rest => return Err(InputError::within(input, rest, "a space").into()),
+        };
+
+        for _ in 0..dist {
+            ropes[0] += step;
+
+            // a knot that stays put leaves the rest of the rope where it was too, so only the
+            // knots up to the first one that doesn't move can have gone anywhere new
+            let mut moved = 1;
+            while moved < ropes.len() {
+                let first = ropes[moved - 1];
+                let second = &mut ropes[moved];
+                if first.chebyshev(second) <= 1 {
+                    break;
+                }
+                *second += (first - *second).signum();
+                moved += 1;
+            }
+
+            if moved > 1 && !std::mem::replace(&mut seen_1[ropes[1]], true) {
+                part1 += 1;
+            }
+            if moved == ropes.len() && !std::mem::replace(&mut seen_2[ropes[9]], true) {
+                part2 += 1;
+            }
+        }
+    }
+
+    (part1, part2).into_result()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::run;
+    use crate::params::DayParams;
+    use crate::DayResult;
+    use std::assert_eq;
+
+    #[test]
+    fn test_example_answers() {
+        let result = run(
+            include_str!("../../../input/2022/test/09.txt"),
+            &DayParams::default(),
+        );
+        assert_eq!(
+            result.unwrap(),
+            DayResult {
+                part1: Some(88.into()),
+                part2: Some(36.into()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_answers() {
+        let result = run(
+            include_str!("../../../input/2022/real/09.txt"),
+            &DayParams::default(),
+        );
+        assert_eq!(
+            result.unwrap(),
+            DayResult {
+                part1: Some(6_044.into()),
+                part2: Some(2_384.into()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_long_walk() {
+        let result = run("L 5000\nU 3\n", &DayParams::default());
+        assert_eq!(
+            result.unwrap(),
+            DayResult {
+                part1: Some(5_002.into()),
+                part2: Some(4_993.into()),
+            }
+        );
+    }
+}
@@ -1,7 +1,9 @@
+use crate::input::InputError;
+use crate::params::DayParams;
 use crate::{Day10Result, DayResult, IntoDayResult};
 use bstr::{BStr, ByteSlice};
 
-pub fn run(input: &'static str, _: bool) -> anyhow::Result<DayResult> {
+pub fn run(input: &str, _: &DayParams) -> anyhow::Result<DayResult> {
     let mut part1 = 0;
     let mut part2 = Day10Result([0; 6]);
 
@@ -23,6 +25,15 @@ pub fn run(input: &'static str, _: bool) -> anyhow::Result<DayResult> {
                 return (part1, part2).into_result();
             }
         } else {
+            let Some(value) = line.strip_prefix(b"addx ") else {
+                return Err(InputError::within(input, line, "noop or addx").into());
+            };
+            let value = value
+                .to_str()
+                .ok()
+                .and_then(|v| v.parse::<isize>().ok())
+                .ok_or_else(|| InputError::within(input, value, "a number"))?;
+
             if (((cycle - 1) % 40) - x).abs() <= 1 {
                 part2.0[((cycle - 1) / 40) as usize] |= 1 << ((cycle - 1) % 40);
             }
@@ -41,7 +52,7 @@ pub fn run(input: &'static str, _: bool) -> anyhow::Result<DayResult> {
             }
 
             cycle += 1;
-            x += unsafe { std::str::from_utf8_unchecked(&line[5..]) }.parse::<isize>()?;
+            x += value;
 
             if cycle <= 220 && (cycle + 20) % 40 == 0 {
                 part1 += cycle * x;
@@ -53,12 +64,13 @@ pub fn run(input: &'static str, _: bool) -> anyhow::Result<DayResult> {
         };
     }
 
-    unreachable!()
+    Err(InputError::at(input, input.len(), "instructions for 240 cycles").into())
 }
 
 #[cfg(test)]
 mod tests {
     use super::run;
+    use crate::params::DayParams;
     use crate::{Day10Result, DayResult};
     use std::assert_eq;
 
@@ -83,7 +95,10 @@ mod tests {
 ######      ######      ######      ####
 #######       #######       #######";
 
-        let result = run(include_str!("../../input/test/10.txt"), false);
+        let result = run(
+            include_str!("../../../input/2022/test/10.txt"),
+            &DayParams::default(),
+        );
         assert_eq!(
             result.unwrap(),
             DayResult {
@@ -95,7 +110,10 @@ mod tests {
 
     #[test]
     fn test_answers() {
-        let result = run(include_str!("../../input/real/10.txt"), false);
+        let result = run(
+            include_str!("../../../input/2022/real/10.txt"),
+            &DayParams::default(),
+        );
         let p2 = "###  #### ###   ##  #### ####   ## ###
 #  #    # #  # #  #    # #       # #  #
 #  #   #  ###  #      #  ###     # ###
@@ -0,0 +1,185 @@
+use crate::input::InputError;
+use crate::params::DayParams;
+use crate::{Answers, Day, IntoAnswer};
+use std::rc::Rc;
+use std::str::FromStr;
+
+pub struct Day11;
+
+impl Day for Day11 {
+    type Parsed<'a> = Vec<Monkey>;
+
+    fn parse<'a>(input: &'a str, _: &DayParams) -> anyhow::Result<Self::Parsed<'a>> {
+        Ok(load_monkeys(input)?)
+    }
+
+    fn part1(monkeys: &Self::Parsed<'_>, _: &DayParams) -> anyhow::Result<Option<Answers>> {
+        play_game::<true>(monkeys.clone(), 20, 0).into_answer()
+    }
+
+    fn part2(monkeys: &Self::Parsed<'_>, _: &DayParams) -> anyhow::Result<Option<Answers>> {
+        let modulo = monkeys.iter().map(|m| m.div).product();
+        play_game::<false>(monkeys.clone(), 10_000, modulo).into_answer()
+    }
+}
+
+fn play_game<const PART1: bool>(mut monkeys: Vec<Monkey>, rounds: usize, modulo: usize) -> usize {
+    let mut monkey: Monkey = Monkey::default();
+    for _ in 0..rounds {
+        for m in 0..monkeys.len() {
+            std::mem::swap(&mut monkey, &mut monkeys[m]);
+
+            monkey.inspections += monkey.items.len();
+            for item in monkey.items.drain(..) {
+                let val = (monkey.op)(item);
+                let new_score = if PART1 { val / 3 } else { val % modulo };
+                monkeys[monkey.indices[(new_score % monkey.div == 0) as usize]]
+                    .items
+                    .push(new_score);
+            }
+
+            std::mem::swap(&mut monkey, &mut monkeys[m]);
+        }
+    }
+
+    let mut highest = [0; 2];
+    for monkey in monkeys {
+        let ins = monkey.inspections;
+        if ins > highest[0] {
+            highest[1] = highest[0];
+            highest[0] = ins;
+        } else if ins > highest[1] {
+            highest[1] = ins;
+        }
+    }
+
+    highest[0] * highest[1]
+}
+
+fn load_monkeys(input: &str) -> Result<Vec<Monkey>, InputError> {
+    let mut monkeys = vec![];
+    let mut targets = vec![];
+
+    for monkey_text in input.split("\n\n") {
+        let mut lines = monkey_text.lines().map(str::trim_start);
+        let mut field = |prefix: &str| match lines.next() {
+            Some(line) => line
+                .strip_prefix(prefix)
+                .ok_or_else(|| InputError::within(input, line, format!("{prefix:?}"))),
+            None => {
+                let end = &monkey_text[monkey_text.len()..];
+                Err(InputError::within(input, end, format!("{prefix:?}")))
+            }
+        };
+
+        field("Monkey ")?;
+        let items = field("Starting items: ")?
+            .split(", ")
+            .map(|n| number(input, n))
+            .collect::<Result<_, _>>()?;
+        let op_text = field("Operation: new = old ")?;
+        let op: Rc<dyn Fn(usize) -> usize> = match op_text.split_once(' ') {
+            Some(("+", "old")) => Rc::new(|val| val + val),
+            Some(("*", "old")) => Rc::new(|val| val * val),
+            Some(("+", int)) => {
+                let int: usize = number(input, int)?;
+                Rc::new(move |val| val + int)
+            }
+            Some(("*", int)) => {
+                let int: usize = number(input, int)?;
+                Rc::new(move |val| val * int)
+            }
+            _ => return Err(InputError::within(input, op_text, "+ or * then a number or old")),
+        };
+        let div_text = field("Test: divisible by ")?;
+        let div = number(input, div_text)?;
+        if div == 0 {
+            return Err(InputError::within(input, div_text, "a divisor other than 0"));
+        }
+        let if_true = field("If true: throw to monkey ")?;
+        let if_false = field("If false: throw to monkey ")?;
+        let indices = [number(input, if_false)?, number(input, if_true)?];
+        targets.push([if_false, if_true]);
+        monkeys.push(Monkey {
+            items,
+            op,
+            div,
+            indices,
+            inspections: 0,
+        });
+    }
+
+    for (monkey, targets) in monkeys.iter().zip(&targets) {
+        for (&index, target) in monkey.indices.iter().zip(targets) {
+            if index >= monkeys.len() {
+                let expected = format!("a monkey from 0 to {}", monkeys.len() - 1);
+                return Err(InputError::within(input, target, expected));
+            }
+        }
+    }
+
+    Ok(monkeys)
+}
+
+fn number<T: FromStr>(input: &str, text: &str) -> Result<T, InputError> {
+    text.parse()
+        .map_err(|_| InputError::within(input, text, "a number"))
+}
+
+#[derive(Clone)]
+pub struct Monkey {
+    items: Vec<usize>,
+    op: Rc<dyn Fn(usize) -> usize>,
+    div: usize,
+    indices: [usize; 2],
+    inspections: usize,
+}
+
+impl Default for Monkey {
+    fn default() -> Self {
+        Monkey {
+            items: Vec::new(),
+            op: Rc::new(|a| a + a),
+            div: 0,
+            indices: [0; 2],
+            inspections: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day11;
+    use crate::params::DayParams;
+    use crate::{Day, DayResult};
+
+    #[test]
+    fn test_example_answers() {
+        let result = Day11::solve(
+            include_str!("../../../input/2022/test/11.txt"),
+            &DayParams::default(),
+        );
+        assert_eq!(
+            result.unwrap(),
+            DayResult {
+                part1: Some(10_605.into()),
+                part2: Some(2_713_310_158_usize.into()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_answers() {
+        let result = Day11::solve(
+            include_str!("../../../input/2022/real/11.txt"),
+            &DayParams::default(),
+        );
+        assert_eq!(
+            result.unwrap(),
+            DayResult {
+                part1: Some(54_036.into()),
+                part2: Some(13_237_873_355_usize.into()),
+            }
+        );
+    }
+}
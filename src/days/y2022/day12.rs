@@ -0,0 +1,103 @@
+use crate::geom::Point2;
+use crate::grid::Grid;
+use crate::input::InputError;
+use crate::params::DayParams;
+use crate::search::bfs;
+use crate::{DayResult, IntoDayResult};
+use anyhow::Context;
+
+type Point = Point2<usize>;
+
+pub fn run(input: &str, _: &DayParams) -> anyhow::Result<DayResult> {
+    let heights = Grid::parse(input, "a height, S or E", |b| {
+        matches!(b, b'a'..=b'z' | b'S' | b'E').then_some(b)
+    })?;
+    if heights.position(|&b| b == b'E').is_none() {
+        return Err(InputError::at(input, input.len(), "the best signal, E").into());
+    }
+
+    let p1_start = heights
+        .position(|&b| b == b'S')
+        .ok_or_else(|| InputError::at(input, input.len(), "the start, S"))?;
+
+    let part1 = part_1([p1_start], &heights);
+    let part2 = part_2(&heights);
+    (
+        part1.context("the best signal can't be reached from the start")?,
+        part2.context("the best signal can't be reached from any lowest square")?,
+    )
+        .into_result()
+}
+
+fn part_1(start: impl IntoIterator<Item = Point>, heights: &Grid<u8>) -> Option<usize> {
+    bfs(start, |&point| get_next(point, heights), |&point| heights[point] == b'E').cost()
+}
+
+fn part_2(heights: &Grid<u8>) -> Option<usize> {
+    let starts = heights
+        .positions()
+        .filter_map(|(point, &b)| (b == b'a').then_some(point));
+
+    part_1(starts, heights)
+}
+
+fn elevation(b: u8) -> u8 {
+    match b {
+        b'S' => b'a',
+        b'E' => b'z',
+        b => b,
+    }
+}
+
+fn get_next(curr: Point, heights: &Grid<u8>) -> impl Iterator<Item = Point> + '_ {
+    let val = elevation(heights[curr]);
+
+    let neighbours = [
+        curr.x.checked_sub(1).map(|x| Point2::new(x, curr.y)),
+        Some(Point2::new(curr.x + 1, curr.y)),
+        curr.y.checked_sub(1).map(|y| Point2::new(curr.x, y)),
+        Some(Point2::new(curr.x, curr.y + 1)),
+    ];
+    neighbours.into_iter().flatten().filter(move |&next| {
+        heights
+            .get(next)
+            .is_some_and(|&a| elevation(a) <= val + 1)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::run;
+    use crate::params::DayParams;
+    use crate::DayResult;
+
+    #[test]
+    fn test_example_answers() {
+        let result = run(
+            include_str!("../../../input/2022/test/12.txt"),
+            &DayParams::default(),
+        );
+        assert_eq!(
+            result.unwrap(),
+            DayResult {
+                part1: Some(31.into()),
+                part2: Some(29.into()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_answers() {
+        let result = run(
+            include_str!("../../../input/2022/real/12.txt"),
+            &DayParams::default(),
+        );
+        assert_eq!(
+            result.unwrap(),
+            DayResult {
+                part1: Some(408.into()),
+                part2: Some(399.into()),
+            }
+        );
+    }
+}
@@ -1,3 +1,5 @@
+use crate::input::InputError;
+use crate::params::DayParams;
 use crate::{DayResult, IntoDayResult};
 use nom::branch::alt;
 use nom::bytes::complete::tag;
@@ -8,7 +10,8 @@ use nom::IResult;
 use std::cmp::Ordering;
 use std::fmt::{Debug, Formatter};
 
-pub fn run(mut input: &'static str, _: bool) -> anyhow::Result<DayResult> {
+pub fn run(whole: &str, _: &DayParams) -> anyhow::Result<DayResult> {
+    let mut input = whole;
     let div_1 = Packet(vec![Item::Packet(Packet(vec![Item::Value(2)]))]);
     let div_2 = Packet(vec![Item::Packet(Packet(vec![Item::Value(6)]))]);
 
@@ -18,7 +21,8 @@ pub fn run(mut input: &'static str, _: bool) -> anyhow::Result<DayResult> {
     let mut d1 = 1;
     let mut d2 = 2;
     loop {
-        let (_input, (x, y)) = parse_packets(input)?;
+        let (_input, (x, y)) = parse_packets(input)
+            .map_err(|e| InputError::nom(whole, e, "a pair of packets like [1,[2,3]]"))?;
 
         if x < y {
             part1 += pair;
@@ -136,12 +140,16 @@ impl Debug for Item {
 #[cfg(test)]
 mod tests {
     use super::run;
+    use crate::params::DayParams;
     use crate::DayResult;
     use std::assert_eq;
 
     #[test]
     fn test_example_answers() {
-        let result = run(include_str!("../../input/test/13.txt"), false);
+        let result = run(
+            include_str!("../../../input/2022/test/13.txt"),
+            &DayParams::default(),
+        );
         assert_eq!(
             result.unwrap(),
             DayResult {
@@ -153,7 +161,10 @@ mod tests {
 
     #[test]
     fn test_answers() {
-        let result = run(include_str!("../../input/real/13.txt"), false);
+        let result = run(
+            include_str!("../../../input/2022/real/13.txt"),
+            &DayParams::default(),
+        );
         assert_eq!(
             result.unwrap(),
             DayResult {
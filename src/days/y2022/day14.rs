@@ -0,0 +1,161 @@
+use crate::geom::Point2;
+use crate::infinite::InfiniteGrid2;
+use crate::input::InputError;
+use crate::params::DayParams;
+use crate::{DayResult, IntoDayResult};
+use nom::bytes::complete::tag;
+use nom::sequence::{pair, preceded};
+use nom::IResult;
+use std::cmp::max;
+
+pub fn run(input: &str, _: &DayParams) -> anyhow::Result<DayResult> {
+    let mut max_y = 0;
+
+    for line in input.lines() {
+        let (mut rem, (_, y)) = parse_coord_pair(line).map_err(|e| path_error(input, e))?;
+        max_y = max(max_y, y);
+
+        while let Ok((_rem, (_, y))) = parse_subsequent_pair(rem) {
+            max_y = max(max_y, y);
+            rem = _rem;
+        }
+        if !rem.is_empty() {
+            return Err(InputError::within(input, rem, "-> and the next point").into());
+        }
+    }
+
+    let lowest = max_y;
+    let floor = lowest + 2;
+    let mut world = InfiniteGrid2::new(false);
+
+    // the floor goes on forever, but the sand can only spread out as far sideways as it falls, so
+    // it's drawn in just wide enough to catch all of it
+    for x in SOURCE.x - floor - 1..=SOURCE.x + floor + 1 {
+        world[Point2::new(x, floor)] = true;
+    }
+
+    for line in input.lines() {
+        let (mut rem, start) = parse_coord_pair(line).map_err(|e| path_error(input, e))?;
+        let mut curr = Point2::from(start);
+        world[curr] = true;
+
+        while let Ok((_rem, pair)) = parse_subsequent_pair(rem) {
+            let next_point = Point2::from(pair);
+            let unit = (next_point - curr).signum();
+
+            while curr != next_point {
+                curr += unit;
+                world[curr] = true;
+            }
+
+            rem = _rem;
+        }
+    }
+
+    let mut sand = SOURCE;
+    let mut sand_history = vec![sand];
+
+    let mut part1 = 0;
+    while sand.y < lowest {
+        let pot_down = Point2::new(sand.x, sand.y + 1);
+        let pot_left = Point2::new(sand.x - 1, sand.y + 1);
+        let pot_right = Point2::new(sand.x + 1, sand.y + 1);
+        if !world[pot_down] {
+            sand_history.push(sand);
+            sand = pot_down;
+        } else if !world[pot_left] {
+            sand_history.push(sand);
+            sand = pot_left;
+        } else if !world[pot_right] {
+            sand_history.push(sand);
+            sand = pot_right;
+        } else {
+            world[sand] = true;
+            part1 += 1;
+            if let Some(prev) = sand_history.pop() {
+                sand = prev;
+            } else {
+                sand = SOURCE;
+            }
+        }
+    }
+
+    let mut part2 = part1;
+    loop {
+        let pot_down = Point2::new(sand.x, sand.y + 1);
+        let pot_left = Point2::new(sand.x - 1, sand.y + 1);
+        let pot_right = Point2::new(sand.x + 1, sand.y + 1);
+        if !world[pot_down] {
+            sand_history.push(sand);
+            sand = pot_down;
+        } else if !world[pot_left] {
+            sand_history.push(sand);
+            sand = pot_left;
+        } else if !world[pot_right] {
+            sand_history.push(sand);
+            sand = pot_right;
+        } else if let Some(prev) = sand_history.pop() {
+            world[sand] = true;
+            part2 += 1;
+            sand = prev;
+        } else {
+            break;
+        }
+    }
+
+    (part1, part2).into_result()
+}
+
+const SOURCE: Point2<i32> = Point2::new(500, 0);
+
+fn path_error<'a>(input: &'a str, err: nom::Err<nom::error::Error<&'a str>>) -> InputError {
+    InputError::nom(input, err, "a path like 498,4 -> 498,6 -> 496,6")
+}
+
+fn parse_coord_pair(input: &str) -> IResult<&str, (i32, i32)> {
+    pair(
+        nom::character::complete::i32,
+        preceded(tag(","), nom::character::complete::i32),
+    )(input)
+}
+
+fn parse_subsequent_pair(input: &str) -> IResult<&str, (i32, i32)> {
+    preceded(tag(" -> "), parse_coord_pair)(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::run;
+    use crate::params::DayParams;
+    use crate::DayResult;
+
+    #[test]
+    fn test_example_answers() {
+        let result = run(
+            include_str!("../../../input/2022/test/14.txt"),
+            &DayParams::default(),
+        );
+        assert_eq!(
+            result.unwrap(),
+            DayResult {
+                part1: Some(24.into()),
+                part2: Some(93.into()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_answers() {
+        let result = run(
+            include_str!("../../../input/2022/real/14.txt"),
+            &DayParams::default(),
+        );
+        assert_eq!(
+            result.unwrap(),
+            DayResult {
+                part1: Some(1_072.into()),
+                part2: Some(24_659.into()),
+            }
+        );
+    }
+}
@@ -0,0 +1,192 @@
+use crate::geom::Point2;
+use crate::input::InputError;
+use crate::params::{DayParams, Param};
+use crate::timeout::check_cancelled;
+use crate::{Answers, Day, IntoAnswer};
+use anyhow::bail;
+use fxhash::FxBuildHasher;
+use nom::bytes::complete::tag;
+use nom::combinator::map;
+use nom::sequence::{delimited, preceded, tuple};
+use nom::IResult;
+use std::cmp::Reverse;
+use std::collections::HashSet;
+
+pub struct Day15;
+
+pub const PARAMS: &[Param] = &[
+    // the row to count the positions a beacon can't be in
+    Param {
+        name: "row",
+        real: 2_000_000,
+        test: 10,
+    },
+    // the search area for the distress beacon
+    Param {
+        name: "max_x",
+        real: 4_000_000,
+        test: 20,
+    },
+    Param {
+        name: "max_y",
+        real: 4_000_000,
+        test: 20,
+    },
+];
+
+impl Day for Day15 {
+    type Parsed<'a> = Vec<(Point2<i64>, Point2<i64>)>;
+
+    fn parse<'a>(whole: &'a str, _: &DayParams) -> anyhow::Result<Self::Parsed<'a>> {
+        let mut input = whole;
+        let mut sensors_and_beacons = vec![];
+        while !input.is_empty() {
+            let (_input, pair) = parse_sensors_and_beacon(input).map_err(|e| {
+                let expected = "a line like Sensor at x=2, y=18: closest beacon is at x=-2, y=15";
+                InputError::nom(whole, e, expected)
+            })?;
+            input = _input;
+            sensors_and_beacons.push(pair);
+        }
+
+        Ok(sensors_and_beacons)
+    }
+
+    fn part1(
+        sensors_and_beacons: &Self::Parsed<'_>,
+        params: &DayParams,
+    ) -> anyhow::Result<Option<Answers>> {
+        let row: i64 = params.get("row")?;
+
+        let mut beacons_on_line = HashSet::with_hasher(FxBuildHasher::default());
+        let mut seen = HashSet::with_hasher(FxBuildHasher::default());
+        for &(sensor, beacon) in sensors_and_beacons {
+            if beacon.y == row {
+                beacons_on_line.insert(beacon.x);
+            }
+
+            let manhattan = sensor.manhattan(&beacon);
+            let dist = (sensor.y - row).abs();
+            let rem = manhattan - dist;
+
+            if rem < 0 {
+                continue;
+            }
+
+            for x in sensor.x - rem..=sensor.x + rem {
+                seen.insert(x);
+            }
+        }
+
+        (seen.len() - beacons_on_line.len()).into_answer()
+    }
+
+    fn part2(
+        sensors_and_beacons: &Self::Parsed<'_>,
+        params: &DayParams,
+    ) -> anyhow::Result<Option<Answers>> {
+        let mut sensors_and_manhattan = sensors_and_beacons
+            .iter()
+            .map(|(sensor, beacon)| (*sensor, sensor.manhattan(beacon)))
+            .collect::<Vec<_>>();
+
+        sensors_and_manhattan.sort_unstable_by_key(|&(_, man)| Reverse(man));
+
+        solve_part2(
+            &sensors_and_manhattan,
+            params.get("max_x")?,
+            params.get("max_y")?,
+        )?
+        .into_answer()
+    }
+}
+
+fn solve_part2(
+    sensors_and_manhattan: &[(Point2<i64>, i64)],
+    max_x: i64,
+    max_y: i64,
+) -> anyhow::Result<i64> {
+    let mut x = 0;
+    while x <= max_x {
+        check_cancelled()?;
+        let mut y = 0;
+        while y <= max_y {
+            let mut ok = true;
+            for &(sensor, man) in sensors_and_manhattan {
+                let x_dist = (sensor.x - x).abs();
+                if x_dist > man {
+                    continue;
+                }
+                let y_dist = man - x_dist;
+
+                let min_y = sensor.y - y_dist;
+                let max_y = sensor.y + y_dist;
+                if y >= min_y && y <= max_y {
+                    ok = false;
+                    y = max_y + 1;
+                    break;
+                }
+            }
+
+            if ok {
+                return Ok(4_000_000 * x + y);
+            }
+        }
+
+        x += 1;
+    }
+
+    bail!("every position is in range of a sensor");
+}
+
+fn parse_sensors_and_beacon(input: &str) -> IResult<&str, (Point2<i64>, Point2<i64>)> {
+    map(
+        tuple((
+            preceded(tag("Sensor at x="), nom::character::complete::i64),
+            preceded(tag(", y="), nom::character::complete::i64),
+            preceded(
+                tag(": closest beacon is at x="),
+                nom::character::complete::i64,
+            ),
+            delimited(tag(", y="), nom::character::complete::i64, tag("\n")),
+        )),
+        |(x1, y1, x2, y2)| (Point2::new(x1, y1), Point2::new(x2, y2)),
+    )(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day15, PARAMS};
+    use crate::params::DayParams;
+    use crate::{Day, DayResult};
+
+    #[test]
+    fn test_example_answers() {
+        let result = Day15::solve(
+            include_str!("../../../input/2022/test/15.txt"),
+            &DayParams::defaults(PARAMS, true),
+        );
+        assert_eq!(
+            result.unwrap(),
+            DayResult {
+                part1: Some(26.into()),
+                part2: Some(56_000_011.into()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_answers() {
+        let result = Day15::solve(
+            include_str!("../../../input/2022/real/15.txt"),
+            &DayParams::defaults(PARAMS, false),
+        );
+        assert_eq!(
+            result.unwrap(),
+            DayResult {
+                part1: Some(5_125_700.into()),
+                part2: Some(11_379_394_658_764_i64.into()),
+            }
+        );
+    }
+}
@@ -1,4 +1,8 @@
-use crate::{DayResult, IntoDayResult};
+use crate::input::InputError;
+use crate::params::{DayParams, Param};
+use crate::search::bfs;
+use crate::timeout::is_cancelled;
+use crate::{Answers, Day, IntoAnswer};
 use fxhash::FxBuildHasher;
 use nom::branch::alt;
 use nom::bytes::complete::tag;
@@ -7,73 +11,117 @@ use nom::multi::separated_list1;
 use nom::sequence::{delimited, preceded, tuple};
 use nom::IResult;
 use std::cmp::{max, min};
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{HashMap, HashSet};
 use std::hash::BuildHasher;
 
-pub fn run(mut input: &'static str, _: bool) -> anyhow::Result<DayResult> {
-    let mut valves = HashMap::with_hasher(FxBuildHasher::default());
-
-    while !input.is_empty() {
-        let (_input, (name, flow_rate, leads_to)) = parse_row(input)?;
-        input = _input;
-        valves.insert(
-            name,
-            Valve {
-                flow_rate,
-                leads_to,
-            },
-        );
-    }
+pub struct Day16;
+
+pub const PARAMS: &[Param] = &[
+    Param::new("part1_minutes", 30),
+    // 4 minutes are spent teaching the elephant
+    Param::new("part2_minutes", 26),
+];
+
+pub struct Cave<'a> {
+    valves: HashMap<&'a str, Valve<'a>, FxBuildHasher>,
+    distances: HashMap<(&'a str, &'a str), u64, FxBuildHasher>,
+    worth_turning_on: Vec<&'a str>,
+}
 
-    let mut distances = HashMap::with_hasher(FxBuildHasher::default());
-    for &v1 in valves.keys() {
-        for &v2 in valves.keys() {
-            let dist = dist_to(v1, v2, &valves);
-            distances.insert((v1, v2), dist);
-            distances.insert((v2, v1), dist);
+impl Day for Day16 {
+    type Parsed<'a> = Cave<'a>;
+
+    fn parse<'a>(whole: &'a str, _: &DayParams) -> anyhow::Result<Self::Parsed<'a>> {
+        let mut input = whole;
+        let mut valves = HashMap::with_hasher(FxBuildHasher::default());
+
+        while !input.is_empty() {
+            let (_input, (name, flow_rate, leads_to)) = parse_row(input).map_err(|e| {
+                let expected = "a valve like Valve AA has flow rate=0; tunnel leads to valve BB";
+                InputError::nom(whole, e, expected)
+            })?;
+            input = _input;
+            valves.insert(
+                name,
+                Valve {
+                    flow_rate,
+                    leads_to,
+                },
+            );
+        }
+
+        let missing = valves
+            .values()
+            .flat_map(|valve| &valve.leads_to)
+            .filter(|name| !valves.contains_key(*name))
+            .min_by_key(|name| name.as_ptr());
+        if let Some(missing) = missing {
+            return Err(InputError::within(whole, missing, "a valve with a scan of its own").into());
         }
+        if !valves.contains_key("AA") {
+            return Err(InputError::at(whole, whole.len(), "a scan of valve AA").into());
+        }
+
+        let mut distances = HashMap::with_hasher(FxBuildHasher::default());
+        for &v1 in valves.keys() {
+            for &v2 in valves.keys() {
+                let dist = dist_to(v1, v2, &valves);
+                distances.insert((v1, v2), dist);
+                distances.insert((v2, v1), dist);
+            }
+        }
+
+        let worth_turning_on = valves
+            .iter()
+            .filter(|(_, info)| info.flow_rate != 0)
+            .map(|(name, _)| *name)
+            .collect::<Vec<_>>();
+
+        Ok(Cave {
+            valves,
+            distances,
+            worth_turning_on,
+        })
     }
 
-    let worth_turning_on = valves
-        .iter()
-        .filter(|(_, info)| info.flow_rate != 0)
-        .map(|(name, _)| *name)
-        .collect::<Vec<_>>();
-
-    let turned_on = &mut HashSet::with_hasher(FxBuildHasher::default());
-
-    let part_1 = solve_part1(
-        30,
-        "AA",
-        &valves,
-        &distances,
-        &worth_turning_on,
-        turned_on,
-        0,
-        0,
-    );
-
-    turned_on.clear();
-
-    let start = Journey {
-        valve: "AA",
-        remaining_turns: 0,
-        future_flow: 0,
-    };
-    let part_2 = solve_part2(
-        26,
-        0,
-        0,
-        start.clone(),
-        start.clone(),
-        false,
-        &valves,
-        &distances,
-        &worth_turning_on,
-        turned_on,
-    );
-
-    (part_1, part_2).into_result()
+    fn part1(cave: &Self::Parsed<'_>, params: &DayParams) -> anyhow::Result<Option<Answers>> {
+        let turned_on = &mut HashSet::with_hasher(FxBuildHasher::default());
+
+        solve_part1(
+            params.get("part1_minutes")?,
+            "AA",
+            &cave.valves,
+            &cave.distances,
+            &cave.worth_turning_on,
+            turned_on,
+            0,
+            0,
+        )
+        .into_answer()
+    }
+
+    fn part2(cave: &Self::Parsed<'_>, params: &DayParams) -> anyhow::Result<Option<Answers>> {
+        let turned_on = &mut HashSet::with_hasher(FxBuildHasher::default());
+
+        let start = Journey {
+            valve: "AA",
+            remaining_turns: 0,
+            future_flow: 0,
+        };
+        solve_part2(
+            params.get("part2_minutes")?,
+            0,
+            0,
+            start.clone(),
+            start,
+            false,
+            &cave.valves,
+            &cave.distances,
+            &cave.worth_turning_on,
+            turned_on,
+        )
+        .into_answer()
+    }
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -89,7 +137,7 @@ fn solve_part2<'a, H: BuildHasher>(
     worth_turning_on: &[&'a str],
     turned_on: &mut HashSet<&'a str, H>,
 ) -> u64 {
-    if turns_remaining == 0 {
+    if turns_remaining == 0 || is_cancelled() {
         return cumulative;
     }
 
@@ -253,29 +301,11 @@ struct Journey<'a> {
 }
 
 fn dist_to(start: &str, end: &str, valves: &HashMap<&str, Valve, impl BuildHasher>) -> u64 {
-    let mut queue = VecDeque::new();
-    queue.push_back((start, 0));
-
-    let mut seen = HashSet::new();
-
-    while let Some((state, dist)) = queue.pop_front() {
-        if !seen.insert(state) {
-            continue;
-        }
-
-        if state == end {
-            return dist;
-        }
-
-        let neighbours = valves
-            .get(state)
-            .map(|v| v.leads_to.iter().map(|&s| (s, dist + 1)))
-            .unwrap();
+    let leads_to = |valve: &_| valves[valve].leads_to.iter().copied();
+    let search = bfs([start], leads_to, |&valve| valve == end);
 
-        queue.extend(neighbours);
-    }
-
-    unreachable!();
+    // too far away to ever be worth walking to
+    search.cost().map_or(u64::MAX, |dist| dist as u64)
 }
 
 #[derive(Debug)]
@@ -301,13 +331,18 @@ fn parse_row(input: &str) -> IResult<&str, (&str, u64, Vec<&str>)> {
 
 #[cfg(test)]
 mod tests {
-    use super::run;
-    use crate::DayResult;
+    use super::{Day16, PARAMS};
+    use crate::input::InputError;
+    use crate::params::DayParams;
+    use crate::{Day, DayResult};
     use std::assert_eq;
 
     #[test]
     fn test_example_answers() {
-        let result = run(include_str!("../../input/test/16.txt"), false);
+        let result = Day16::solve(
+            include_str!("../../../input/2022/test/16.txt"),
+            &DayParams::defaults(PARAMS, true),
+        );
         assert_eq!(
             result.unwrap(),
             DayResult {
@@ -317,9 +352,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_missing_valve() {
+        let input = "Valve AA has flow rate=0; tunnels lead to valves BB, CC\n\
+                     Valve BB has flow rate=13; tunnel leads to valve AA\n";
+        let err = Day16::parse(input, &DayParams::defaults(PARAMS, true)).err();
+        let err = err.unwrap().downcast::<InputError>().unwrap();
+        assert_eq!((err.line, err.column), (1, 54));
+        assert_eq!(err.expected, "a valve with a scan of its own");
+    }
+
     // #[test]
     // fn test_answers() {
-    //     let result = run(include_str!("../../input/real/16.txt"), false);
+    //     let result = Day16::solve(
+    //         include_str!("../../../input/2022/real/16.txt"),
+    //         &DayParams::defaults(PARAMS, false),
+    //     );
     //     assert_eq!(
     //         result.unwrap(),
     //         DayResult {
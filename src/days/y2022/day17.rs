@@ -0,0 +1,277 @@
+use crate::cycle::find_cycle;
+use crate::geom::{Point2, Vec2};
+use crate::input::InputError;
+use crate::params::{DayParams, Param};
+use crate::{Answers, Day, IntoAnswer};
+use anyhow::Context;
+use std::cmp::min;
+use std::ops::Add;
+
+// y grows upwards here, from the floor
+const SHAPES: &[&[Vec2<i64>]] = &[
+    &[
+        Vec2::new(0, 0),
+        Vec2::new(1, 0),
+        Vec2::new(2, 0),
+        Vec2::new(3, 0),
+    ],
+    &[
+        Vec2::new(1, 0),
+        Vec2::new(0, 1),
+        Vec2::new(1, 1),
+        Vec2::new(2, 1),
+        Vec2::new(1, 2),
+    ],
+    &[
+        Vec2::new(0, 0),
+        Vec2::new(1, 0),
+        Vec2::new(2, 0),
+        Vec2::new(2, 1),
+        Vec2::new(2, 2),
+    ],
+    &[
+        Vec2::new(0, 0),
+        Vec2::new(0, 1),
+        Vec2::new(0, 2),
+        Vec2::new(0, 3),
+    ],
+    &[
+        Vec2::new(0, 0),
+        Vec2::new(0, 1),
+        Vec2::new(1, 0),
+        Vec2::new(1, 1),
+    ],
+];
+
+pub struct Day17;
+
+pub const PARAMS: &[Param] = &[
+    Param::new("part1_rocks", 2_022),
+    Param::new("part2_rocks", 1_000_000_000_000),
+];
+
+impl Day for Day17 {
+    type Parsed<'a> = &'a [u8];
+
+    fn parse<'a>(input: &'a str, _: &DayParams) -> anyhow::Result<Self::Parsed<'a>> {
+        let jets = input.trim().as_bytes();
+        if let Some(bad) = jets.iter().position(|&b| b != b'<' && b != b'>') {
+            return Err(InputError::within(input, &jets[bad..], "< or >").into());
+        }
+        if jets.is_empty() {
+            return Err(InputError::at(input, 0, "< or >").into());
+        }
+        Ok(jets)
+    }
+
+    fn part1(jets: &Self::Parsed<'_>, params: &DayParams) -> anyhow::Result<Option<Answers>> {
+        let mut chamber = Chamber::new(jets);
+        for _ in 0..params.get("part1_rocks")? {
+            chamber.drop_rock();
+        }
+        chamber.highest_settled.into_answer()
+    }
+
+    fn part2(jets: &Self::Parsed<'_>, params: &DayParams) -> anyhow::Result<Option<Answers>> {
+        let rocks = params.get("part2_rocks")?;
+        // every pairing of rock and jet comes round within this many rocks, so a tower that's
+        // going to settle into a cycle will have done so after a few times round
+        let search = SEARCH_LAPS * SHAPES.len() * jets.len();
+        let run = find_cycle(
+            &mut Chamber::new(jets),
+            min(rocks, search),
+            Chamber::drop_rock,
+            Chamber::fingerprint,
+            |chamber| chamber.highest_settled,
+        );
+        run.at(rocks)
+            .context("the tower never settles into a pattern")?
+            .into_answer()
+    }
+}
+
+/// How many times round every rock and jet the tower gets to start repeating itself.
+const SEARCH_LAPS: usize = 4;
+
+/// Every column of a row, a bit each.
+const FULL_ROW: u8 = (1 << 7) - 1;
+
+struct Chamber<'a> {
+    jets: &'a [u8],
+    // the next jet to push a rock
+    jet: usize,
+    rocks: usize,
+    // a bit for each column of each row that has rock in it, up to the highest rock
+    rows: Vec<u8>,
+    highest_settled: i64,
+}
+
+impl Chamber<'_> {
+    fn new(jets: &[u8]) -> Chamber<'_> {
+        Chamber {
+            jets,
+            jet: 0,
+            rocks: 0,
+            rows: Vec::new(),
+            highest_settled: 0,
+        }
+    }
+
+    fn drop_rock(&mut self) {
+        let offset = Point2::new(2, self.highest_settled + 3);
+        let mut shape = Shape {
+            offset,
+            shape: SHAPES[self.rocks % SHAPES.len()],
+        };
+
+        loop {
+            // try and push sideways
+            let dir = self.jets[self.jet];
+            self.jet = (self.jet + 1) % self.jets.len();
+            let pushed = match dir {
+                b'<' => shape.left(&self.rows),
+                _ => shape.right(&self.rows),
+            };
+            if let Some(new_shape) = pushed {
+                shape = new_shape;
+            }
+
+            // then try and move down. if fail, make solid and stop
+            match shape.down(&self.rows) {
+                Some(new_shape) => shape = new_shape,
+                None => break,
+            }
+        }
+
+        for coord in shape.coords() {
+            let y = coord.y as usize;
+            if self.rows.len() <= y {
+                self.rows.resize(y + 1, 0);
+            }
+            self.rows[y] |= 1 << coord.x;
+        }
+        self.highest_settled = self.rows.len() as i64;
+        self.rocks += 1;
+    }
+
+    /// What the next rocks will be falling onto: which rock and jet come next, and every empty
+    /// cell a rock could still get to from above, row by row down from the top. Rocks only move
+    /// down and sideways, so a cell is reachable if it's open and next to a reachable cell above
+    /// or beside it, and nothing else about the tower can make a difference to them.
+    fn fingerprint(&self) -> (usize, usize, Vec<u8>) {
+        let mut reachable = Vec::new();
+        let mut reach = FULL_ROW;
+        for &row in self.rows.iter().rev() {
+            let open = !row & FULL_ROW;
+            reach &= open;
+            loop {
+                let spread = (reach | reach << 1 | reach >> 1) & open;
+                if spread == reach {
+                    break;
+                }
+                reach = spread;
+            }
+            if reach == 0 {
+                break;
+            }
+            reachable.push(reach);
+        }
+        (self.rocks % SHAPES.len(), self.jet, reachable)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+struct Shape {
+    offset: Point2<i64>,
+    shape: &'static [Vec2<i64>],
+}
+
+impl Shape {
+    fn coords(&self) -> impl Iterator<Item = Point2<i64>> + '_ {
+        self.shape.iter().map(|&p| self.offset + p)
+    }
+
+    fn down(&self, rows: &[u8]) -> Option<Shape> {
+        self.shift(Vec2::new(0, -1), rows)
+    }
+
+    fn left(&self, rows: &[u8]) -> Option<Shape> {
+        self.shift(Vec2::new(-1, 0), rows)
+    }
+
+    fn right(&self, rows: &[u8]) -> Option<Shape> {
+        self.shift(Vec2::new(1, 0), rows)
+    }
+
+    fn shift(&self, direction: Vec2<i64>, rows: &[u8]) -> Option<Shape> {
+        let shifted = *self + direction;
+        let legal = shifted.coords().all(|p| {
+            let filled = |row: &u8| row & 1 << p.x != 0;
+            (0..7).contains(&p.x) && p.y >= 0 && !rows.get(p.y as usize).is_some_and(filled)
+        });
+        if legal {
+            Some(shifted)
+        } else {
+            None
+        }
+    }
+}
+
+impl Add<Vec2<i64>> for Shape {
+    type Output = Shape;
+
+    fn add(self, rhs: Vec2<i64>) -> Self::Output {
+        let Shape { offset, shape } = self;
+        let offset = offset + rhs;
+        Shape { offset, shape }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day17, PARAMS};
+    use crate::params::DayParams;
+    use crate::{run_phases, Day, DayResult, Parts};
+
+    #[test]
+    fn test_example_answers() {
+        let result = Day17::solve(
+            include_str!("../../../input/2022/test/17.txt"),
+            &DayParams::defaults(PARAMS, true),
+        );
+        assert_eq!(
+            result.unwrap(),
+            DayResult {
+                part1: Some(3_068.into()),
+                part2: Some(1_514_285_714_288_i64.into()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_answers() {
+        let result = Day17::solve(
+            include_str!("../../../input/2022/real/17.txt"),
+            &DayParams::defaults(PARAMS, false),
+        );
+        assert_eq!(
+            result.unwrap(),
+            DayResult {
+                part1: Some(3_127.into()),
+                part2: Some(1_542_941_176_480_i64.into()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_one_jet() {
+        // nothing ever lands on the right, so the well there goes all the way down to the floor
+        // and the tower never looks the same twice
+        let params = DayParams::defaults(PARAMS, false);
+        let (result, _) = run_phases::<Day17>("<", &params, Parts::Part1).unwrap();
+        assert_eq!(result.part1, Some(4_448.into()));
+
+        let err = run_phases::<Day17>("<", &params, Parts::Part2).unwrap_err();
+        assert_eq!(err.to_string(), "the tower never settles into a pattern");
+    }
+}
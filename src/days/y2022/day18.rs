@@ -1,5 +1,7 @@
-use crate::days::day18::SurfaceType::{Exterior, Interior, Surface};
-use crate::{DayResult, IntoDayResult};
+use crate::days::y2022::day18::SurfaceType::{Exterior, Interior, Surface};
+use crate::input::InputError;
+use crate::params::DayParams;
+use crate::{Answers, Day, IntoAnswer};
 use fxhash::FxBuildHasher;
 use nom::bytes::complete::tag;
 use nom::combinator::map;
@@ -9,17 +11,37 @@ use std::cmp::{max, min};
 use std::collections::{HashMap, HashSet};
 use std::hash::BuildHasher;
 
-pub fn run(mut input: &'static str, _: bool) -> anyhow::Result<DayResult> {
-    let mut points = HashSet::with_hasher(FxBuildHasher::default());
+pub struct Day18;
 
-    while !input.is_empty() {
-        let (_input, line) = parse_line(input)?;
-        input = _input;
-        points.insert(line);
+impl Day for Day18 {
+    type Parsed<'a> = HashSet<[i64; 3], FxBuildHasher>;
+
+    fn parse<'a>(whole: &'a str, _: &DayParams) -> anyhow::Result<Self::Parsed<'a>> {
+        let mut input = whole;
+        let mut points = HashSet::with_hasher(FxBuildHasher::default());
+
+        while !input.is_empty() {
+            let (_input, line) = parse_line(input)
+                .map_err(|e| InputError::nom(whole, e, "a cube like 2,2,2"))?;
+            input = _input;
+            points.insert(line);
+        }
+
+        Ok(points)
     }
 
+    fn part1(points: &Self::Parsed<'_>, _: &DayParams) -> anyhow::Result<Option<Answers>> {
+        surface_area(points).into_answer()
+    }
+
+    fn part2(points: &Self::Parsed<'_>, _: &DayParams) -> anyhow::Result<Option<Answers>> {
+        exterior_surface_area(points).into_answer()
+    }
+}
+
+fn surface_area(points: &HashSet<[i64; 3], impl BuildHasher>) -> i32 {
     let mut part1 = 0;
-    for &point in &points {
+    for &point in points {
         let mods = [
             [1, 0, 0],
             [-1, 0, 0],
@@ -39,6 +61,10 @@ pub fn run(mut input: &'static str, _: bool) -> anyhow::Result<DayResult> {
         }
     }
 
+    part1
+}
+
+fn exterior_surface_area(points: &HashSet<[i64; 3], impl BuildHasher>) -> i32 {
     let (mins, maxes) = points.iter().fold(([i64::MAX; 3], [0; 3]), |(mi, ma), &b| {
         (
             [min(mi[0], b[0]), min(mi[1], b[1]), min(mi[2], b[2])],
@@ -52,7 +78,7 @@ pub fn run(mut input: &'static str, _: bool) -> anyhow::Result<DayResult> {
     let mut visited = HashSet::new();
 
     let mut part2 = 0;
-    for &point in &points {
+    for &point in points {
         let mods = [
             [1, 0, 0],
             [-1, 0, 0],
@@ -74,7 +100,7 @@ pub fn run(mut input: &'static str, _: bool) -> anyhow::Result<DayResult> {
         }
     }
 
-    (part1, part2).into_result()
+    part2
 }
 
 #[derive(Copy, Clone, Eq, PartialEq)]
@@ -172,12 +198,16 @@ fn parse_line(line: &str) -> IResult<&str, [i64; 3]> {
 
 #[cfg(test)]
 mod tests {
-    use super::run;
-    use crate::DayResult;
+    use super::Day18;
+    use crate::params::DayParams;
+    use crate::{Day, DayResult};
 
     #[test]
     fn test_example_answers() {
-        let result = run(include_str!("../../input/test/18.txt"), false);
+        let result = Day18::solve(
+            include_str!("../../../input/2022/test/18.txt"),
+            &DayParams::default(),
+        );
         assert_eq!(
             result.unwrap(),
             DayResult {
@@ -189,7 +219,10 @@ mod tests {
 
     #[test]
     fn test_answers() {
-        let result = run(include_str!("../../input/real/18.txt"), false);
+        let result = Day18::solve(
+            include_str!("../../../input/2022/real/18.txt"),
+            &DayParams::default(),
+        );
         assert_eq!(
             result.unwrap(),
             DayResult {
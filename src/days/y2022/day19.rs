@@ -1,4 +1,7 @@
-use crate::{DayResult, IntoDayResult};
+use crate::input::InputError;
+use crate::params::{DayParams, Param};
+use crate::timeout::is_cancelled;
+use crate::{Answers, Day, IntoAnswer};
 use arrayvec::ArrayVec;
 use nom::bytes::complete::tag;
 use nom::character::complete::u32 as nom_u32;
@@ -11,28 +14,55 @@ use std::fmt::Debug;
 use std::hash::Hash;
 use std::ops::{AddAssign, Sub, SubAssign};
 
-pub fn run(mut input: &'static str, _: bool) -> anyhow::Result<DayResult> {
-    let mut seen = 0;
-    let mut part1 = 0;
-    let mut part2 = 1;
-    while !input.is_empty() {
-        let (_input, blueprint) = Blueprint::parse_line(input)?;
-        input = _input;
-
-        part1 += blueprint.number * State::default().best_score_from_state(blueprint);
-        if seen < 3 {
-            part2 *= State::part2().best_score_from_state(blueprint);
-            seen += 1;
+pub struct Day19;
+
+pub const PARAMS: &[Param] = &[
+    Param::new("part1_minutes", 24),
+    Param::new("part2_minutes", 32),
+    // how many blueprints the elephants didn't eat
+    Param::new("part2_blueprints", 3),
+];
+
+impl Day for Day19 {
+    type Parsed<'a> = Vec<Blueprint>;
+
+    fn parse<'a>(whole: &'a str, _: &DayParams) -> anyhow::Result<Self::Parsed<'a>> {
+        let mut input = whole;
+        let mut blueprints = vec![];
+        while !input.is_empty() {
+            let (_input, blueprint) = Blueprint::parse_line(input)
+                .map_err(|e| InputError::nom(whole, e, "a blueprint, all on one line"))?;
+            input = _input;
+            blueprints.push(blueprint);
         }
+
+        Ok(blueprints)
+    }
+
+    fn part1(blueprints: &Self::Parsed<'_>, params: &DayParams) -> anyhow::Result<Option<Answers>> {
+        let start = State::with_minutes(params.get("part1_minutes")?);
+        blueprints
+            .iter()
+            .map(|&blueprint| blueprint.number * start.best_score_from_state(blueprint))
+            .sum::<u32>()
+            .into_answer()
     }
 
-    (part1, part2).into_result()
+    fn part2(blueprints: &Self::Parsed<'_>, params: &DayParams) -> anyhow::Result<Option<Answers>> {
+        let start = State::with_minutes(params.get("part2_minutes")?);
+        blueprints
+            .iter()
+            .take(params.get("part2_blueprints")?)
+            .map(|&blueprint| start.best_score_from_state(blueprint))
+            .product::<u32>()
+            .into_answer()
+    }
 }
 
 impl State {
-    fn part2() -> State {
+    fn with_minutes(turns_remaining: u32) -> State {
         State {
-            turns_remaining: 32,
+            turns_remaining,
             ..Default::default()
         }
     }
@@ -40,6 +70,9 @@ impl State {
     fn best_score_from_state(self, blueprint: Blueprint) -> u32 {
         let mut best = self.materials.geode + self.robots.geode_robots * self.turns_remaining;
         for poss in self.next_states(blueprint) {
+            if is_cancelled() {
+                break;
+            }
             best = max(best, poss.best_score_from_state(blueprint));
         }
         best
@@ -210,14 +243,6 @@ impl SubAssign<GeodeRobotCost> for Materials {
     }
 }
 
-#[derive(Clone, Debug, Eq, PartialEq, Default, Hash)]
-struct PurchaseTimes {
-    ore: Vec<u32>,
-    clay: Vec<u32>,
-    obsidian: Vec<u32>,
-    geode: Vec<u32>,
-}
-
 #[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
 struct State {
     turns_remaining: u32,
@@ -262,7 +287,7 @@ struct Robots {
 }
 
 #[derive(Debug, Default, Copy, Clone)]
-struct Blueprint {
+pub struct Blueprint {
     number: u32,
     ore_robot: OreRobotCost,
     clay_robot: ClayRobotCost,
@@ -347,12 +372,16 @@ impl Blueprint {
 
 #[cfg(test)]
 mod tests {
-    use super::run;
-    use crate::DayResult;
+    use super::{Day19, PARAMS};
+    use crate::params::DayParams;
+    use crate::{Day, DayResult};
 
     #[test]
     fn test_example_answers() {
-        let result = run(include_str!("../../input/test/19.txt"), false);
+        let result = Day19::solve(
+            include_str!("../../../input/2022/test/19.txt"),
+            &DayParams::defaults(PARAMS, true),
+        );
         assert_eq!(
             result.unwrap(),
             DayResult {
@@ -364,7 +393,10 @@ mod tests {
 
     #[test]
     fn test_answers() {
-        let result = run(include_str!("../../input/real/19.txt"), false);
+        let result = Day19::solve(
+            include_str!("../../../input/2022/real/19.txt"),
+            &DayParams::defaults(PARAMS, false),
+        );
         assert_eq!(
             result.unwrap(),
             DayResult {
@@ -1,4 +1,7 @@
-use crate::{DayResult, IntoDayResult};
+use crate::input::InputError;
+use crate::params::{DayParams, Param};
+use crate::{Answers, Day, IntoAnswer};
+use anyhow::Context;
 use nom::bytes::complete::tag;
 use nom::character::complete as num;
 use nom::combinator::all_consuming;
@@ -7,16 +10,44 @@ use nom::sequence::terminated;
 use nom::IResult;
 use std::ops::Rem;
 
-pub fn run(input: &'static str, _: bool) -> anyhow::Result<DayResult> {
-    let (_, numbers) = parse_numbers(input)?;
+pub struct Day20;
 
-    let part1 = solve(numbers.iter().copied(), 1);
-    let part2 = solve(numbers.iter().map(|n| n * 811_589_153), 10);
+pub const PARAMS: &[Param] = &[
+    Param::new("part1_mixes", 1),
+    Param::new("key", 811_589_153),
+    Param::new("part2_mixes", 10),
+];
 
-    (part1, part2).into_result()
+impl Day for Day20 {
+    type Parsed<'a> = Vec<i64>;
+
+    fn parse<'a>(input: &'a str, _: &DayParams) -> anyhow::Result<Self::Parsed<'a>> {
+        let (_, numbers) =
+            parse_numbers(input).map_err(|e| InputError::nom(input, e, "a number"))?;
+        if !numbers.contains(&0) {
+            return Err(InputError::at(input, input.len(), "a 0 somewhere").into());
+        }
+        Ok(numbers)
+    }
+
+    fn part1(numbers: &Self::Parsed<'_>, params: &DayParams) -> anyhow::Result<Option<Answers>> {
+        solve(numbers.iter().copied(), params.get("part1_mixes")?)
+            .context(NO_ZERO)?
+            .into_answer()
+    }
+
+    fn part2(numbers: &Self::Parsed<'_>, params: &DayParams) -> anyhow::Result<Option<Answers>> {
+        let key: i64 = params.get("key")?;
+        solve(numbers.iter().map(|n| n * key), params.get("part2_mixes")?)
+            .context(NO_ZERO)?
+            .into_answer()
+    }
 }
 
-fn solve(nums: impl IntoIterator<Item = i64>, mixes: usize) -> i64 {
+const NO_ZERO: &str = "there's no 0 to count the grove coordinates from";
+
+/// The grove coordinates, if there's a 0 to count them from.
+fn solve(nums: impl IntoIterator<Item = i64>, mixes: usize) -> Option<i64> {
     let numbers = nums.into_iter().collect::<Vec<_>>();
     // vec of original index to new index
     let mut indices = (0..numbers.len()).collect::<Vec<_>>();
@@ -28,9 +59,11 @@ fn solve(nums: impl IntoIterator<Item = i64>, mixes: usize) -> i64 {
         .collect::<Vec<_>>();
     // when vec 2 is updated, also update the other vec's index accordingly
 
-    let zero_ind = numbers.iter().position(|e| e.val == 0).unwrap();
+    let zero_ind = numbers.iter().position(|e| e.val == 0)?;
 
     let len = indices.len() as i64;
+    // there's nowhere for a number on its own to move to
+    let mixes = if len > 1 { mixes } else { 0 };
 
     for _ in 0..mixes {
         for i in 0..indices.len() {
@@ -75,9 +108,11 @@ fn solve(nums: impl IntoIterator<Item = i64>, mixes: usize) -> i64 {
 
     let zero_ind = indices[zero_ind];
 
-    numbers[(zero_ind + 1_000) % numbers.len()].val
-        + numbers[(zero_ind + 2_000) % numbers.len()].val
-        + numbers[(zero_ind + 3_000) % numbers.len()].val
+    Some(
+        numbers[(zero_ind + 1_000) % numbers.len()].val
+            + numbers[(zero_ind + 2_000) % numbers.len()].val
+            + numbers[(zero_ind + 3_000) % numbers.len()].val,
+    )
 }
 
 fn parse_numbers(input: &str) -> IResult<&str, Vec<i64>> {
@@ -92,12 +127,17 @@ struct Entry {
 
 #[cfg(test)]
 mod tests {
-    use super::run;
-    use crate::DayResult;
+    use super::{Day20, PARAMS};
+    use crate::input::InputError;
+    use crate::params::DayParams;
+    use crate::{Day, DayResult};
 
     #[test]
     fn test_example_answers() {
-        let result = run(include_str!("../../input/test/20.txt"), false);
+        let result = Day20::solve(
+            include_str!("../../../input/2022/test/20.txt"),
+            &DayParams::defaults(PARAMS, true),
+        );
         assert_eq!(
             result.unwrap(),
             DayResult {
@@ -109,7 +149,10 @@ mod tests {
 
     #[test]
     fn test_answers() {
-        let result = run(include_str!("../../input/real/20.txt"), false);
+        let result = Day20::solve(
+            include_str!("../../../input/2022/real/20.txt"),
+            &DayParams::defaults(PARAMS, false),
+        );
         assert_eq!(
             result.unwrap(),
             DayResult {
@@ -118,4 +161,21 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_missing_zero() {
+        let err = Day20::parse("1\n2\n-3\n", &DayParams::defaults(PARAMS, true)).unwrap_err();
+        let err = err.downcast::<InputError>().unwrap();
+        assert_eq!((err.line, err.column), (4, 1));
+        assert_eq!(err.expected, "a 0 somewhere");
+
+        let result = Day20::solve("0\n", &DayParams::defaults(PARAMS, true));
+        assert_eq!(
+            result.unwrap(),
+            DayResult {
+                part1: Some(0.into()),
+                part2: Some(0.into()),
+            }
+        );
+    }
 }
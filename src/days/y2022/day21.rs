@@ -1,5 +1,6 @@
+use crate::input::InputError;
+use crate::params::DayParams;
 use crate::{DayResult, IntoDayResult};
-use anyhow::Context;
 use nom::branch::alt;
 use nom::bytes::complete::{tag, take_while_m_n};
 use nom::character::is_alphabetic;
@@ -7,18 +8,20 @@ use nom::combinator::{map, map_res};
 use nom::sequence::tuple;
 use nom::IResult;
 use std::cell::Cell;
-use std::collections::{HashMap, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::fmt::Debug;
 use std::rc::Rc;
 
-pub fn run(input: &'static str, _: bool) -> anyhow::Result<DayResult> {
-    let mut input = input.as_bytes();
+pub fn run(input: &str, _: &DayParams) -> anyhow::Result<DayResult> {
+    let whole = input.as_bytes();
+    let mut input = whole;
 
     let mut ops_optimised = HashMap::new();
     let mut to_optimise = VecDeque::new();
 
     while !input.is_empty() {
-        let (_input, (k, op)) = parse_line(input)?;
+        let (_input, (k, op)) = parse_line(input)
+            .map_err(|e| InputError::nom(whole, e, "a monkey like root: pppw + sjmn"))?;
         input = _input;
         match op {
             OpUnoptimised::Literal(v) => {
@@ -34,6 +37,19 @@ pub fn run(input: &'static str, _: bool) -> anyhow::Result<DayResult> {
         }
     }
 
+    let names = ops_optimised
+        .keys()
+        .chain(to_optimise.iter().map(|(name, _)| name))
+        .collect::<HashSet<_>>();
+    let missing = to_optimise
+        .iter()
+        .flat_map(|(_, (first, _, second))| [first, second])
+        .filter(|name| !names.contains(name))
+        .min_by_key(|name| name.as_ptr());
+    if let Some(missing) = missing {
+        return Err(InputError::within(whole, missing, "a monkey that's in the list").into());
+    }
+
     while let Some(entry @ (name, (first, sign, second))) = to_optimise.pop_front() {
         match ops_optimised.get(first).zip(ops_optimised.get(second)) {
             None => {
@@ -54,11 +70,16 @@ pub fn run(input: &'static str, _: bool) -> anyhow::Result<DayResult> {
         }
     }
 
-    let root = ops_optimised.get("root").context("failed to find root")?;
+    let (root_name, root) = ops_optimised
+        .get_key_value("root")
+        .ok_or_else(|| InputError::at(whole, whole.len(), "a monkey named root"))?;
     let part1 = root.value_of();
 
     let (l, r) = match root.as_ref() {
-        Op::Literal(_) => unreachable!(),
+        Op::Literal(_) => {
+            let expected = "root to wait for two other monkeys";
+            return Err(InputError::within(whole, root_name, expected).into());
+        }
         Op::Compound { first, second, .. } => (first, second),
     };
 
@@ -67,8 +88,11 @@ pub fn run(input: &'static str, _: bool) -> anyhow::Result<DayResult> {
 
     let mut base = 0;
 
-    let Op::Literal(humn) =  ops_optimised.get("humn").context("expected to find humn")?.as_ref() else {
-        unreachable!();
+    let (humn_name, humn) = ops_optimised
+        .get_key_value("humn")
+        .ok_or_else(|| InputError::at(whole, whole.len(), "a monkey named humn"))?;
+    let Op::Literal(humn) = humn.as_ref() else {
+        return Err(InputError::within(whole, humn_name, "humn to yell a number").into());
     };
 
     humn.set(humn.get() - 500);
@@ -104,7 +128,7 @@ pub fn run(input: &'static str, _: bool) -> anyhow::Result<DayResult> {
     (part1, part2).into_result()
 }
 
-fn parse_line(line: &[u8]) -> IResult<&[u8], (&str, OpUnoptimised)> {
+fn parse_line(line: &[u8]) -> IResult<&[u8], (&str, OpUnoptimised<'_>)> {
     map(
         tuple((
             map_res(take_while_m_n(4, 4, is_alphabetic), |b| {
@@ -203,11 +227,15 @@ impl Sign {
 #[cfg(test)]
 mod tests {
     use super::run;
+    use crate::params::DayParams;
     use crate::DayResult;
 
     #[test]
     fn test_example_answers() {
-        let result = run(include_str!("../../input/test/21.txt"), false);
+        let result = run(
+            include_str!("../../../input/2022/test/21.txt"),
+            &DayParams::default(),
+        );
         assert_eq!(
             result.unwrap(),
             DayResult {
@@ -219,7 +247,10 @@ mod tests {
 
     #[test]
     fn test_answers() {
-        let result = run(include_str!("../../input/real/21.txt"), false);
+        let result = run(
+            include_str!("../../../input/2022/real/21.txt"),
+            &DayParams::default(),
+        );
         assert_eq!(
             result.unwrap(),
             DayResult {
@@ -0,0 +1,526 @@
+use crate::days::y2022::day22::GroundType::{Floor, Wall};
+use crate::geom::{Direction, Point2, Vec2};
+use crate::grid::Grid;
+use crate::input::InputError;
+use crate::params::DayParams;
+use crate::{DayResult, IntoDayResult};
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::combinator::map;
+use nom::IResult;
+
+type Point = Point2<i64>;
+type Map = Grid<Option<GroundType>>;
+
+pub fn run(input: &str, _: &DayParams) -> anyhow::Result<DayResult> {
+    let (world_map, instructions) = input
+        .split_once("\n\n")
+        .ok_or_else(|| InputError::at(input, input.len(), "a blank line, then the path"))?;
+
+    let world = Grid::parse_padded(world_map, None, "an open tile, a wall or a space", |b| {
+        match b {
+            b'.' => Some(Some(Floor)),
+            b'#' => Some(Some(Wall)),
+            b' ' => Some(None),
+            _ => None,
+        }
+    })?;
+
+    let side_len = world_map
+        .lines()
+        .map(|line| {
+            line.as_bytes()
+                .iter()
+                .filter(|&&b| matches!(b, b'.' | b'#'))
+                .count()
+        })
+        .min()
+        .ok_or_else(|| InputError::at(input, 0, "a map"))? as i64;
+
+    let position = (world.rows().next())
+        .and_then(|row| row.iter().position(Option::is_some))
+        .map(|x| Point2::new(x as i64, 0))
+        .ok_or_else(|| InputError::at(input, 0, "a tile on the top row"))?;
+
+    let facing = Direction::Right;
+
+    let instructions = instructions.trim();
+
+    let part1 = solver(
+        input,
+        instructions,
+        position,
+        facing,
+        &world,
+        side_len,
+        try_move_p1,
+    )?;
+    let part2 = solver(
+        input,
+        instructions,
+        position,
+        facing,
+        &world,
+        side_len,
+        try_move_p2,
+    )?;
+
+    (part1, part2).into_result()
+}
+
+fn solver(
+    input: &str,
+    mut instructions: &str,
+    mut position: Point,
+    mut facing: Direction,
+    world: &Map,
+    side_len: i64,
+    move_fn: impl Fn(Point, &mut Direction, &Map, i64) -> Result<Option<Point>, Point>,
+) -> anyhow::Result<i64> {
+    let mut to_move = true;
+    while !instructions.is_empty() {
+        if to_move {
+            let (_instructions, dist): (&str, i64) =
+                parse_dist(instructions)
+                .map_err(|e| InputError::nom(input, e, "a distance to move"))?;
+            instructions = _instructions;
+            for _ in 0..dist {
+                match move_fn(position, &mut facing, world, side_len) {
+                    Ok(Some(new_position)) => position = new_position,
+                    Ok(None) => break,
+                    Err(at) => return Err(fold_error(input, at).into()),
+                }
+            }
+        } else {
+            let (_instructions, turn): (&str, Turn) =
+                parse_turn(instructions)
+                .map_err(|e| InputError::nom(input, e, "L or R"))?;
+            instructions = _instructions;
+
+            facing = match turn {
+                Turn::Left => facing.turn_left(),
+                Turn::Right => facing.turn_right(),
+            };
+        }
+
+        to_move = !to_move;
+    }
+
+    let facing = match facing {
+        Direction::Right => 0,
+        Direction::Down => 1,
+        Direction::Left => 2,
+        Direction::Up => 3,
+    };
+
+    let row = position.y + 1;
+    let col = position.x + 1;
+
+    Ok(1000 * row + 4 * col + facing)
+}
+
+/// Where a step forward goes, `None` if it's into a wall, or where the map doesn't join up.
+fn try_move_p1(
+    position: Point,
+    facing: &mut Direction,
+    world: &Map,
+    side_len: i64,
+) -> Result<Option<Point>, Point> {
+    let front_pos = position + facing.step();
+    match tile(world, front_pos) {
+        Some(Floor) => Ok(Some(front_pos)),
+        Some(Wall) => Ok(None),
+        None => {
+            for i in (1..=4).rev() {
+                let potential = front_pos - facing.step() * (side_len * i);
+                if let Some(tile) = tile(world, potential) {
+                    return match tile {
+                        Wall => Ok(None),
+                        Floor => Ok(Some(potential)),
+                    };
+                }
+            }
+            Err(front_pos)
+        }
+    }
+}
+
+fn try_move_p2(
+    position: Point,
+    facing: &mut Direction,
+    world: &Map,
+    side_len: i64,
+) -> Result<Option<Point>, Point> {
+    let front_pos = position + facing.step();
+    match tile(world, front_pos) {
+        Some(Floor) => Ok(Some(front_pos)),
+        Some(Wall) => Ok(None),
+        None => {
+            let state = CubeState::default();
+            let wanted = match facing {
+                Direction::Right => state.right,
+                Direction::Down => state.bottom,
+                Direction::Left => state.left,
+                Direction::Up => state.top,
+            };
+
+            let mut visited: [[bool; 6]; 6] = Default::default();
+            visit(&mut visited, position, side_len);
+            let landing = cube_search(
+                position,
+                position,
+                state,
+                *facing,
+                wanted,
+                side_len,
+                world,
+                &mut visited,
+            );
+            match landing {
+                Some(Landing::Open(p, turns)) => {
+                    for _ in 0..turns {
+                        *facing = facing.turn_right();
+                    }
+                    Ok(Some(p))
+                }
+                Some(Landing::Wall) => Ok(None),
+                Some(Landing::Broken(at)) => Err(at),
+                // no face of the net joins on here
+                None => Err(front_pos),
+            }
+        }
+    }
+}
+
+/// Where stepping off the edge of a face comes down on the face it folds onto.
+enum Landing {
+    /// an open tile, and how many quarter turns right it leaves you facing
+    Open(Point, i64),
+    Wall,
+    /// somewhere the net doesn't fold into a cube
+    Broken(Point),
+}
+
+/// Marks the face `point` is on as visited, saying whether it wasn't already.
+fn visit(visited: &mut [[bool; 6]; 6], point: Point, side_len: i64) -> bool {
+    let face = visited
+        .get_mut((point.x / side_len) as usize)
+        .and_then(|column| column.get_mut((point.y / side_len) as usize));
+    match face {
+        Some(face) if !*face => {
+            *face = true;
+            true
+        }
+        _ => false,
+    }
+}
+
+/// The error for a map that doesn't join up at `at`, pointing at that place in the map, or as
+/// near it as the map's lines go.
+fn fold_error(input: &str, at: Point) -> InputError {
+    let mut offset = 0;
+    let mut lines = input.split('\n').take_while(|line| !line.is_empty());
+    for _ in 0..at.y.max(0) {
+        match lines.next() {
+            Some(line) => offset += line.len() + 1,
+            None => break,
+        }
+    }
+    let line_len = input[offset..].find('\n').unwrap_or(input.len() - offset);
+    let column = at.x.clamp(0, line_len as i64) as usize;
+    InputError::at(input, offset + column, "a map that folds into a cube")
+}
+
+#[derive(Eq, PartialEq, Hash, Debug, Copy, Clone)]
+struct CubeState {
+    front: u8,
+    back: u8,
+    left: u8,
+    right: u8,
+    top: u8,
+    bottom: u8,
+}
+
+impl Default for CubeState {
+    fn default() -> Self {
+        CubeState {
+            front: 1,
+            back: 2,
+            left: 3,
+            right: 4,
+            top: 5,
+            bottom: 6,
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn cube_search(
+    origin: Point,
+    point: Point,
+    state: CubeState,
+    original_dir: Direction,
+    goal: u8,
+    side_len: i64,
+    world: &Map,
+    visited: &mut [[bool; 6]; 6],
+) -> Option<Landing> {
+    if state.front == goal {
+        let dir_to_original = match (state.left, state.right, state.top, state.bottom) {
+            (1, _, _, _) => Direction::Left,
+            (_, 1, _, _) => Direction::Right,
+            (_, _, 1, _) => Direction::Up,
+            (_, _, _, 1) => Direction::Down,
+            _ => return Some(Landing::Broken(point)),
+        };
+
+        let origin_distances = Vec2::new(origin.x % side_len, origin.y % side_len);
+
+        let top_of_square = Point2::new(
+            (point.x / side_len) * side_len,
+            (point.y / side_len) * side_len,
+        );
+
+        let (point, turns) = match (original_dir, dir_to_original) {
+            (Direction::Left, Direction::Left) => (
+                top_of_square + Vec2::new(0, (side_len - 1) - origin_distances.y),
+                2,
+            ),
+            (Direction::Left, Direction::Right) => (
+                top_of_square + Vec2::new(side_len - 1, origin_distances.y),
+                0,
+            ),
+            (Direction::Left, Direction::Up) => (
+                top_of_square + Vec2::new(origin_distances.y, 0),
+                3,
+            ),
+            (Direction::Left, Direction::Down) => (
+                top_of_square + Vec2::new((side_len - 1) - origin_distances.y, side_len - 1),
+                1,
+            ),
+            (Direction::Right, Direction::Left) => (
+                top_of_square + Vec2::new(0, origin_distances.y),
+                0,
+            ),
+            (Direction::Right, Direction::Right) => (
+                top_of_square + Vec2::new(side_len - 1, (side_len - 1) - origin_distances.y),
+                2,
+            ),
+            (Direction::Right, Direction::Up) => (
+                top_of_square + Vec2::new((side_len - 1) - origin_distances.y, 0),
+                1,
+            ),
+            (Direction::Right, Direction::Down) => (
+                top_of_square + Vec2::new(origin_distances.y, side_len - 1),
+                3,
+            ),
+            (Direction::Up, Direction::Left) => (
+                top_of_square + Vec2::new(0, origin_distances.x),
+                1,
+            ),
+            (Direction::Up, Direction::Right) => (
+                top_of_square + Vec2::new(side_len - 1, (side_len - 1) - origin_distances.x),
+                3,
+            ),
+            (Direction::Up, Direction::Up) => (
+                top_of_square + Vec2::new((side_len - 1) - origin_distances.x, 0),
+                2,
+            ),
+            (Direction::Up, Direction::Down) => (
+                top_of_square + Vec2::new(origin_distances.x, side_len - 1),
+                0,
+            ),
+            (Direction::Down, Direction::Left) => (
+                top_of_square + Vec2::new(0, (side_len - 1) - origin_distances.x),
+                3,
+            ),
+            (Direction::Down, Direction::Right) => (
+                top_of_square + Vec2::new(side_len - 1, origin_distances.x),
+                1,
+            ),
+            (Direction::Down, Direction::Up) => (
+                top_of_square + Vec2::new(origin_distances.x, 0),
+                0,
+            ),
+            (Direction::Down, Direction::Down) => (
+                top_of_square + Vec2::new((side_len - 1) - origin_distances.x, side_len - 1),
+                2,
+            ),
+        };
+        return Some(match tile(world, point) {
+            None => Landing::Broken(point),
+            Some(Wall) => Landing::Wall,
+            Some(Floor) => Landing::Open(point, turns),
+        });
+    }
+
+    let up = point + Direction::Up.step() * side_len;
+    if tile(world, up).is_some() && visit(visited, up, side_len) {
+        let new_state = CubeState {
+            top: state.back,
+            back: state.bottom,
+            bottom: state.front,
+            front: state.top,
+            ..state
+        };
+        if let solution @ Some(_) = cube_search(
+            origin,
+            up,
+            new_state,
+            original_dir,
+            goal,
+            side_len,
+            world,
+            visited,
+        ) {
+            return solution;
+        }
+    }
+
+    let down = point + Direction::Down.step() * side_len;
+    if tile(world, down).is_some() && visit(visited, down, side_len) {
+        let new_state = CubeState {
+            top: state.front,
+            back: state.top,
+            bottom: state.back,
+            front: state.bottom,
+            ..state
+        };
+        if let solution @ Some(_) = cube_search(
+            origin,
+            down,
+            new_state,
+            original_dir,
+            goal,
+            side_len,
+            world,
+            visited,
+        ) {
+            return solution;
+        }
+    }
+
+    let left = point + Direction::Left.step() * side_len;
+    if tile(world, left).is_some() && visit(visited, left, side_len) {
+        let new_state = CubeState {
+            left: state.back,
+            back: state.right,
+            right: state.front,
+            front: state.left,
+            ..state
+        };
+        if let solution @ Some(_) = cube_search(
+            origin,
+            left,
+            new_state,
+            original_dir,
+            goal,
+            side_len,
+            world,
+            visited,
+        ) {
+            return solution;
+        }
+    }
+
+    let right = point + Direction::Right.step() * side_len;
+    if tile(world, right).is_some() && visit(visited, right, side_len) {
+        let new_state = CubeState {
+            left: state.front,
+            back: state.left,
+            right: state.back,
+            front: state.right,
+            ..state
+        };
+        if let solution @ Some(_) = cube_search(
+            origin,
+            right,
+            new_state,
+            original_dir,
+            goal,
+            side_len,
+            world,
+            visited,
+        ) {
+            return solution;
+        }
+    }
+
+    None
+}
+
+#[derive(Debug, Copy, Clone)]
+enum GroundType {
+    Wall,
+    Floor,
+}
+
+fn tile(world: &Map, point: Point) -> Option<GroundType> {
+    world.get(point).copied().flatten()
+}
+
+fn parse_dist(line: &str) -> IResult<&str, i64> {
+    nom::character::complete::i64(line)
+}
+
+fn parse_turn(line: &str) -> IResult<&str, Turn> {
+    alt((map(tag("L"), |_| Turn::Left), map(tag("R"), |_| Turn::Right)))(line)
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Turn {
+    Left,
+    Right,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::run;
+    use crate::input::InputError;
+    use crate::params::DayParams;
+    use crate::DayResult;
+
+    #[test]
+    fn test_example_answers() {
+        let result = run(
+            include_str!("../../../input/2022/test/22.txt"),
+            &DayParams::default(),
+        );
+        assert_eq!(
+            result.unwrap(),
+            DayResult {
+                part1: Some(6032.into()),
+                part2: Some(5031.into()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_answers() {
+        let result = run(
+            include_str!("../../../input/2022/real/22.txt"),
+            &DayParams::default(),
+        );
+        assert_eq!(
+            result.unwrap(),
+            DayResult {
+                part1: Some(165094.into()),
+                part2: Some(95316.into()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_broken_cube() {
+        // without the face at the bottom right, the net doesn't fold into a cube
+        let example = include_str!("../../../input/2022/test/22.txt");
+        let (map, path) = example.split_once("\n\n").unwrap();
+        let map = map.lines().map(|line| &line[..line.len().min(12)]);
+        let input = format!("{}\n\n{path}", map.collect::<Vec<_>>().join("\n"));
+
+        let err = run(&input, &DayParams::default()).unwrap_err();
+        let err = err.downcast::<InputError>().unwrap();
+        assert_eq!((err.line, err.column), (6, 13));
+        assert_eq!(err.expected, "a map that folds into a cube");
+    }
+}
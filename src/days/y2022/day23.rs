@@ -0,0 +1,149 @@
+use crate::geom::{Direction, Point2};
+use crate::grid::Grid;
+use crate::infinite::InfiniteGrid2;
+use crate::input::InputError;
+use crate::params::DayParams;
+use crate::{DayResult, IntoDayResult};
+use itertools::Itertools;
+
+pub fn run(input: &str, _: &DayParams) -> anyhow::Result<DayResult> {
+    let map = Grid::parse(input, "an elf or an empty tile", |b| match b {
+        b'#' => Some(true),
+        b'.' => Some(false),
+        _ => None,
+    })?;
+    let mut elves = map
+        .positions()
+        .filter(|(_, &elf)| elf)
+        .map(|(p, _)| Point2::new(p.x as i64, p.y as i64))
+        .collect::<Vec<_>>();
+    if elves.is_empty() {
+        return Err(InputError::at(input, input.len(), "at least one elf").into());
+    }
+
+    let mut occupied = InfiniteGrid2::new(false);
+    for &elf in &elves {
+        occupied[elf] = true;
+    }
+
+    // how many elves want each tile this round, put back to 0 once they've moved
+    let mut wanted = InfiniteGrid2::new(0_u8);
+    let mut moves = Vec::new();
+
+    let mut part1 = None;
+    let mut round = 0;
+    let part2 = loop {
+        moves.clear();
+        for (i, &elf) in elves.iter().enumerate() {
+            let has_elf = elf.neighbours8().map(|n| occupied[n]);
+            if !has_elf.contains(&true) {
+                continue;
+            }
+            if let Some(want) = choose_wanted_move(elf, round, has_elf) {
+                wanted[want] += 1;
+                moves.push((i, want));
+            }
+        }
+
+        let mut moved = false;
+        for &(i, want) in &moves {
+            if wanted[want] == 1 {
+                occupied[elves[i]] = false;
+                occupied[want] = true;
+                elves[i] = want;
+                moved = true;
+            }
+        }
+        for &(_, want) in &moves {
+            wanted[want] = 0;
+        }
+
+        round += 1;
+        if round == 10 {
+            part1 = Some(empty_ground(&elves));
+        }
+        if !moved {
+            break round;
+        }
+    };
+    // once nobody moves nobody ever will, so settling early leaves round 10 looking the same
+    let part1 = part1.unwrap_or_else(|| empty_ground(&elves));
+
+    (part1, part2).into_result()
+}
+
+/// The empty tiles in the smallest rectangle around every elf, which is none if there are no elves.
+fn empty_ground(elves: &[Point2<i64>]) -> i64 {
+    let xs = elves.iter().map(|e| e.x).minmax().into_option();
+    let ys = elves.iter().map(|e| e.y).minmax().into_option();
+    let (Some((min_x, max_x)), Some((min_y, max_y))) = (xs, ys) else {
+        return 0;
+    };
+    ((max_y - min_y + 1) * (max_x - min_x + 1)) - elves.len() as i64
+}
+
+fn choose_wanted_move(point: Point2<i64>, rot: usize, has_elf: [bool; 8]) -> Option<Point2<i64>> {
+    // the neighbours to check are indexes into `Direction8::ALL`
+    const MOVES: [([usize; 3], Direction); 4] = [
+        ([7, 0, 1], Direction::Up),
+        ([3, 4, 5], Direction::Down),
+        ([5, 6, 7], Direction::Left),
+        ([1, 2, 3], Direction::Right),
+    ];
+    for i in rot..rot + 4 {
+        let (to_check, direction) = MOVES[i % 4];
+
+        if to_check.into_iter().all(|i| !has_elf[i]) {
+            return Some(point + direction.step());
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{empty_ground, run};
+    use crate::input::InputError;
+    use crate::params::DayParams;
+    use crate::DayResult;
+
+    #[test]
+    fn test_example_answers() {
+        let result = run(
+            include_str!("../../../input/2022/test/23.txt"),
+            &DayParams::default(),
+        );
+        assert_eq!(
+            result.unwrap(),
+            DayResult {
+                part1: Some(110.into()),
+                part2: Some(20.into()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_answers() {
+        let result = run(
+            include_str!("../../../input/2022/real/23.txt"),
+            &DayParams::default(),
+        );
+        assert_eq!(
+            result.unwrap(),
+            DayResult {
+                part1: Some(3_815.into()),
+                part2: Some(893.into()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_no_elves() {
+        let err = run("...\n...\n", &DayParams::default()).unwrap_err();
+        let err = err.downcast::<InputError>().unwrap();
+        assert_eq!((err.line, err.column), (3, 1));
+        assert_eq!(err.expected, "at least one elf");
+        assert_eq!(empty_ground(&[]), 0);
+    }
+}
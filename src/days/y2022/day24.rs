@@ -0,0 +1,144 @@
+use crate::geom::{Direction, Point2, Vec2};
+use crate::grid::Grid;
+use crate::input::InputError;
+use crate::params::DayParams;
+use crate::search::astar;
+use crate::{DayResult, IntoDayResult};
+use anyhow::Context;
+
+pub fn run(input: &str, _: &DayParams) -> anyhow::Result<DayResult> {
+    let (walls, blizzards) = load_world(input)?;
+    let height = walls.height() as i64;
+    let width = walls.width() as i64;
+
+    let start = Point2::new(1, 0);
+
+    let goal = Point2::new(width - 2, height - 1);
+    let unreachable = "the valley can't be crossed";
+    let part1 = traverse(&walls, &blizzards, start, goal, width, height, 0).context(unreachable)?;
+    let back = traverse(&walls, &blizzards, goal, start, width, height, part1)
+        .context(unreachable)?;
+    let part2 = traverse(&walls, &blizzards, start, goal, width, height, part1 + back)
+        .context(unreachable)?;
+
+    (part1, part1 + back + part2).into_result()
+}
+
+fn traverse(
+    walls: &Grid<bool>,
+    blizzards: &[Blizzard],
+    start: Point2<i64>,
+    goal: Point2<i64>,
+    width: i64,
+    height: i64,
+    offset: i64,
+) -> Option<i64> {
+    // the blizzards are back where they started after this many turns, so states that only differ
+    // by a multiple of it are the same
+    let cycle = (width - 2) * (height - 2);
+    let clear = move |point: Point2<i64>, turn: i64| {
+        // stepping off the map is as good as walking into a wall
+        !walls.get(point).copied().unwrap_or(true)
+            && !blizzards
+                .iter()
+                .any(|b| b.offset_by(turn, width, height) == point)
+    };
+
+    // waiting in place, or any of the 4 dirs
+    let moves = [
+        Vec2::default(),
+        Direction::Left.step(),
+        Direction::Right.step(),
+        Direction::Up.step(),
+        Direction::Down.step(),
+    ];
+    let next = |&(position, turn): &(Point2<i64>, i64)| {
+        let turn = (turn + 1) % cycle;
+        moves
+            .into_iter()
+            .map(move |dir| position + dir)
+            .filter(move |&position| clear(position, turn))
+            .map(move |position| ((position, turn), 1))
+    };
+
+    astar(
+        [(start, offset % cycle)],
+        next,
+        |&(position, _)| position.manhattan(&goal),
+        |&(position, _)| position == goal,
+    )
+    .cost()
+}
+
+fn load_world(input: &str) -> Result<(Grid<bool>, Vec<Blizzard>), InputError> {
+    let map = Grid::parse(input, "a wall, a blizzard or open ground", |b| {
+        matches!(b, b'#' | b'.' | b'>' | b'<' | b'^' | b'v').then_some(b)
+    })?;
+
+    let blizzards = map
+        .positions()
+        .filter_map(|(point, &b)| {
+            let direction = match b {
+                b'>' => Direction::Right,
+                b'<' => Direction::Left,
+                b'^' => Direction::Up,
+                b'v' => Direction::Down,
+                _ => return None,
+            };
+            Some(Blizzard {
+                position: Point2::new(point.x as i64, point.y as i64),
+                r#move: direction.step(),
+            })
+        })
+        .collect();
+
+    Ok((map.map(|&b| b == b'#'), blizzards))
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+struct Blizzard {
+    position: Point2<i64>,
+    r#move: Vec2<i64>,
+}
+
+impl Blizzard {
+    fn offset_by(&self, dist: i64, width: i64, height: i64) -> Point2<i64> {
+        let x = ((self.position.x - 1) + dist * self.r#move.x).rem_euclid(width - 2) + 1;
+        let y = ((self.position.y - 1) + dist * self.r#move.y).rem_euclid(height - 2) + 1;
+        Point2::new(x, y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::run;
+    use crate::params::DayParams;
+    use crate::DayResult;
+
+    #[test]
+    fn test_example_answers() {
+        let result = run(
+            include_str!("../../../input/2022/test/24.txt"),
+            &DayParams::default(),
+        );
+        assert_eq!(
+            result.unwrap(),
+            DayResult {
+                part1: Some(18.into()),
+                part2: Some(54.into()),
+            }
+        );
+    }
+
+    // #[test]
+    // fn test_answers() {
+    //     let result = run(include_str!("../../../input/2022/real/24.txt"), &DayParams::default());
+    //     assert_eq!(
+    //         result.unwrap(),
+    //         DayResult {
+    //             part1: Some(311.into()),
+    //             part2: Some(869.into()),
+    //         }
+    //     );
+    // }
+}
@@ -0,0 +1,162 @@
+register_days! {
+    year: 2022,
+    01 => day01 {
+        title: "Calorie Counting",
+        solver: whole!(day01::run),
+        part1: "24000",
+        part2: "45000",
+    },
+    02 => day02 {
+        title: "Rock Paper Scissors",
+        solver: whole!(day02::run),
+        part1: "15",
+        part2: "12",
+    },
+    03 => day03 {
+        title: "Rucksack Reorganization",
+        solver: whole!(day03::run),
+        part1: "157",
+        part2: "70",
+    },
+    04 => day04 {
+        title: "Camp Cleanup",
+        solver: whole!(day04::run),
+        part1: "2",
+        part2: "4",
+    },
+    05 => day05 {
+        title: "Supply Stacks",
+        solver: whole!(day05::run),
+        part1: "CMZ",
+        part2: "MCD",
+    },
+    06 => day06 {
+        title: "Tuning Trouble",
+        solver: whole!(day06::run),
+        part1: "7",
+        part2: "19",
+    },
+    07 => day07 {
+        title: "No Space Left On Device",
+        solver: whole!(day07::run),
+        part1: "95437",
+        part2: "24933642",
+    },
+    08 => day08 {
+        title: "Treetop Tree House",
+        solver: whole!(day08::run),
+        part1: "21",
+        part2: "8",
+    },
+    09 => day09 {
+        title: "Rope Bridge",
+        solver: whole!(day09::run),
+        part1: "88",
+        part2: "36",
+    },
+    10 => day10 {
+        title: "Cathode-Ray Tube",
+        solver: whole!(day10::run),
+        part1: "13140",
+        part2: concat!(
+            "##  ##  ##  ##  ##  ##  ##  ##  ##  ##  \n",
+            "###   ###   ###   ###   ###   ###   ### \n",
+            "####    ####    ####    ####    ####    \n",
+            "#####     #####     #####     #####     \n",
+            "######      ######      ######      ####\n",
+            "#######       #######       #######     \n",
+        ),
+    },
+    11 => day11 {
+        title: "Monkey in the Middle",
+        solver: crate::run_phases::<day11::Day11>,
+        part1: "10605",
+        part2: "2713310158",
+    },
+    12 => day12 {
+        title: "Hill Climbing Algorithm",
+        solver: whole!(day12::run),
+        part1: "31",
+        part2: "29",
+    },
+    13 => day13 {
+        title: "Distress Signal",
+        solver: whole!(day13::run),
+        part1: "13",
+        part2: "140",
+    },
+    14 => day14 {
+        title: "Regolith Reservoir",
+        solver: whole!(day14::run),
+        part1: "24",
+        part2: "93",
+    },
+    15 => day15 {
+        title: "Beacon Exclusion Zone",
+        solver: crate::run_phases::<day15::Day15>,
+        params: day15::PARAMS,
+        part1: "26",
+        part2: "56000011",
+    },
+    16 => day16 {
+        title: "Proboscidea Volcanium",
+        solver: crate::run_phases::<day16::Day16>,
+        params: day16::PARAMS,
+        part1: "1651",
+        part2: "1707",
+        slow: true,
+    },
+    17 => day17 {
+        title: "Pyroclastic Flow",
+        solver: crate::run_phases::<day17::Day17>,
+        params: day17::PARAMS,
+        part1: "3068",
+        part2: "1514285714288",
+    },
+    18 => day18 {
+        title: "Boiling Boulders",
+        solver: crate::run_phases::<day18::Day18>,
+        part1: "64",
+        part2: "58",
+    },
+    19 => day19 {
+        title: "Not Enough Minerals",
+        solver: crate::run_phases::<day19::Day19>,
+        params: day19::PARAMS,
+        part1: "33",
+        part2: "3472",
+        slow: true,
+    },
+    20 => day20 {
+        title: "Grove Positioning System",
+        solver: crate::run_phases::<day20::Day20>,
+        params: day20::PARAMS,
+        part1: "3",
+        part2: "1623178306",
+    },
+    21 => day21 {
+        title: "Monkey Math",
+        solver: whole!(day21::run),
+        part1: "152",
+        part2: "301",
+    },
+    22 => day22 {
+        title: "Monkey Map",
+        solver: whole!(day22::run),
+        part1: "6032",
+        part2: "5031",
+    },
+    23 => day23 {
+        title: "Unstable Diffusion",
+        solver: whole!(day23::run),
+        part1: "110",
+        part2: "20",
+    },
+    24 => day24 {
+        title: "Blizzard Basin",
+        solver: whole!(day24::run),
+        part1: "18",
+        part2: "54",
+        slow: true,
+    },
+}
@@ -0,0 +1,341 @@
+//! Points on a 2D grid and the steps between them, for the days that walk around one.
+//!
+//! Directions follow the puzzle inputs, where `y` grows downwards: [`Direction::Up`] is `-y`.
+//! Days where `y` grows upwards can still use the points and vectors, they just shouldn't name
+//! their directions with these.
+
+use std::fmt::{Debug, Formatter};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use num::{One, Signed, Zero};
+
+/// A position on a grid.
+#[derive(Copy, Clone, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// A step or offset between two [`Point2`]s.
+#[derive(Copy, Clone, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Vec2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Point2<T> {
+        Point2 { x, y }
+    }
+}
+
+impl<T> Vec2<T> {
+    pub const fn new(x: T, y: T) -> Vec2<T> {
+        Vec2 { x, y }
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point2 { x, y }
+    }
+}
+
+/// As coordinates for an [`InfiniteGrid`](crate::infinite::InfiniteGrid).
+impl<T: Into<i64>> From<Point2<T>> for [i64; 2] {
+    #[inline]
+    fn from(point: Point2<T>) -> Self {
+        [point.x.into(), point.y.into()]
+    }
+}
+
+impl<T: Copy + Ord + Sub<Output = T>> Point2<T> {
+    /// The distance along the grid lines, moving one axis at a time.
+    #[inline]
+    pub fn manhattan(&self, other: &Point2<T>) -> T
+    where
+        T: Add<Output = T>,
+    {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+
+    /// The distance when diagonal steps count as one, as a king moves.
+    #[inline]
+    pub fn chebyshev(&self, other: &Point2<T>) -> T {
+        abs_diff(self.x, other.x).max(abs_diff(self.y, other.y))
+    }
+}
+
+impl<T: Copy + Add<Output = T> + Sub<Output = T> + One> Point2<T> {
+    /// The points sharing an edge with this one, in the order of [`Direction::ALL`]. On an
+    /// unsigned grid the caller has to keep away from the zero edges.
+    #[inline]
+    pub fn neighbours4(&self) -> [Point2<T>; 4] {
+        let (x, y, one) = (self.x, self.y, T::one());
+        [
+            Point2::new(x, y - one),
+            Point2::new(x + one, y),
+            Point2::new(x, y + one),
+            Point2::new(x - one, y),
+        ]
+    }
+
+    /// The points sharing an edge or a corner with this one, in the order of
+    /// [`Direction8::ALL`].
+    #[inline]
+    pub fn neighbours8(&self) -> [Point2<T>; 8] {
+        let (x, y, one) = (self.x, self.y, T::one());
+        [
+            Point2::new(x, y - one),
+            Point2::new(x + one, y - one),
+            Point2::new(x + one, y),
+            Point2::new(x + one, y + one),
+            Point2::new(x, y + one),
+            Point2::new(x - one, y + one),
+            Point2::new(x - one, y),
+            Point2::new(x - one, y - one),
+        ]
+    }
+}
+
+impl<T: Signed + Copy> Vec2<T> {
+    /// The unit step, including diagonally, towards where this points.
+    #[inline]
+    pub fn signum(&self) -> Vec2<T> {
+        Vec2::new(self.x.signum(), self.y.signum())
+    }
+}
+
+impl<T: Neg<Output = T>> Vec2<T> {
+    /// Turned a quarter anticlockwise, as seen with `y` growing downwards.
+    #[inline]
+    pub fn left(self) -> Vec2<T> {
+        Vec2::new(self.y, -self.x)
+    }
+
+    /// Turned a quarter clockwise, as seen with `y` growing downwards.
+    #[inline]
+    pub fn right(self) -> Vec2<T> {
+        Vec2::new(-self.y, self.x)
+    }
+}
+
+#[inline]
+fn abs_diff<T: Ord + Sub<Output = T>>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+impl<T: Add<Output = T>> Add<Vec2<T>> for Point2<T> {
+    type Output = Point2<T>;
+
+    #[inline]
+    fn add(self, rhs: Vec2<T>) -> Self::Output {
+        Point2::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub<Vec2<T>> for Point2<T> {
+    type Output = Point2<T>;
+
+    #[inline]
+    fn sub(self, rhs: Vec2<T>) -> Self::Output {
+        Point2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point2<T> {
+    type Output = Vec2<T>;
+
+    #[inline]
+    fn sub(self, rhs: Point2<T>) -> Self::Output {
+        Vec2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Add<Output = T> + Copy> AddAssign<Vec2<T>> for Point2<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Vec2<T>) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Sub<Output = T> + Copy> SubAssign<Vec2<T>> for Point2<T> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Vec2<T>) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Add<Output = T>> Add for Vec2<T> {
+    type Output = Vec2<T>;
+
+    #[inline]
+    fn add(self, rhs: Vec2<T>) -> Self::Output {
+        Vec2::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Vec2<T> {
+    type Output = Vec2<T>;
+
+    #[inline]
+    fn mul(self, rhs: T) -> Self::Output {
+        Vec2::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Vec2<T> {
+    type Output = Vec2<T>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Vec2::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Debug> Debug for Point2<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({:?}, {:?})", self.x, self.y)
+    }
+}
+
+impl<T: Debug> Debug for Vec2<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<{:?}, {:?}>", self.x, self.y)
+    }
+}
+
+/// One of the four ways to step along the grid lines.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise from up.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    #[inline]
+    pub fn turn_left(self) -> Direction {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    #[inline]
+    pub fn turn_right(self) -> Direction {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    #[inline]
+    pub fn reverse(self) -> Direction {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+
+    /// The step taken by moving one square this way.
+    #[inline]
+    pub fn step<T: Zero + One + Neg<Output = T>>(self) -> Vec2<T> {
+        match self {
+            Direction::Up => Vec2::new(T::zero(), -T::one()),
+            Direction::Right => Vec2::new(T::one(), T::zero()),
+            Direction::Down => Vec2::new(T::zero(), T::one()),
+            Direction::Left => Vec2::new(-T::one(), T::zero()),
+        }
+    }
+}
+
+/// One of the eight ways to step to a neighbouring square, diagonals included.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    /// Clockwise from up.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    /// The step taken by moving one square this way.
+    #[inline]
+    pub fn step<T: Zero + One + Neg<Output = T>>(self) -> Vec2<T> {
+        let (zero, one) = (T::zero, T::one);
+        match self {
+            Direction8::Up => Vec2::new(zero(), -one()),
+            Direction8::UpRight => Vec2::new(one(), -one()),
+            Direction8::Right => Vec2::new(one(), zero()),
+            Direction8::DownRight => Vec2::new(one(), one()),
+            Direction8::Down => Vec2::new(zero(), one()),
+            Direction8::DownLeft => Vec2::new(-one(), one()),
+            Direction8::Left => Vec2::new(-one(), zero()),
+            Direction8::UpLeft => Vec2::new(-one(), -one()),
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        Direction8::ALL[direction as usize * 2]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Direction, Direction8, Point2, Vec2};
+
+    #[test]
+    fn points_and_steps_add_up() {
+        let a = Point2::new(1_i64, 5);
+        let b = Point2::new(4_i64, 1);
+        assert_eq!(b - a, Vec2::new(3, -4));
+        assert_eq!(a + (b - a), b);
+        assert_eq!((b - a).signum(), Vec2::new(1, -1));
+        assert_eq!(-(b - a) * 2, Vec2::new(-6, 8));
+        assert_eq!(a.manhattan(&b), 7);
+        assert_eq!(a.chebyshev(&b), 4);
+        assert_eq!(Point2::new(3_usize, 0).manhattan(&Point2::new(1, 2)), 4);
+    }
+
+    #[test]
+    fn directions_turn_and_step() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.reverse(), Direction::Right);
+        for direction in Direction::ALL {
+            let step = direction.step::<i32>();
+            assert_eq!(direction.turn_right().step(), step.right());
+            assert_eq!(direction.turn_left().step(), step.left());
+            assert_eq!(Direction8::from(direction).step(), step);
+        }
+
+        let point = Point2::new(0_i32, 0);
+        let steps = Direction::ALL.map(|direction| point + direction.step());
+        assert_eq!(point.neighbours4(), steps);
+        let steps = Direction8::ALL.map(|direction| point + direction.step());
+        assert_eq!(point.neighbours8(), steps);
+    }
+}
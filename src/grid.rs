@@ -0,0 +1,377 @@
+//! A dense rectangle of cells, for the days whose input is a map.
+//!
+//! Cells are stored row by row. Anything that takes a position takes a [`Point2`] of any integer
+//! type, and positions that are negative or past the edges are simply not in the grid.
+
+use std::fmt::{Debug, Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+use crate::geom::Point2;
+use crate::input::InputError;
+
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// A grid with every cell set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Wraps `cells`, which are in rows of `width`.
+    ///
+    /// # Panics
+    ///
+    /// If `cells` doesn't fill a whole number of rows.
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Grid<T> {
+        let height = cells.len().checked_div(width).unwrap_or(0);
+        assert_eq!(width * height, cells.len(), "cells must fill whole rows");
+        Grid {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    /// Reads a grid with a cell per byte, one row per line. `cell` turns each byte into a cell,
+    /// and errors describe anything it refuses as `expected`. Every row has to be as wide as the
+    /// first.
+    pub fn parse(
+        input: &str,
+        expected: &str,
+        mut cell: impl FnMut(u8) -> Option<T>,
+    ) -> Result<Grid<T>, InputError> {
+        let mut lines = input.lines().peekable();
+        let width = match lines.peek() {
+            Some(line) if !line.is_empty() => line.len(),
+            _ => return Err(InputError::at(input, 0, expected)),
+        };
+
+        let mut cells = Vec::with_capacity(input.len());
+        for line in lines {
+            if line.len() != width {
+                let rest = &line.as_bytes()[line.len().min(width)..];
+                return Err(InputError::within(
+                    input,
+                    rest,
+                    format!("a row {width} wide"),
+                ));
+            }
+            push_cells(&mut cells, input, line, expected, &mut cell)?;
+        }
+
+        Ok(Grid::from_cells(width, cells))
+    }
+
+    /// Like [`Grid::parse`], but rows can have different widths. Short rows are filled out with
+    /// `fill` to make the grid as wide as its widest row.
+    pub fn parse_padded(
+        input: &str,
+        fill: T,
+        expected: &str,
+        mut cell: impl FnMut(u8) -> Option<T>,
+    ) -> Result<Grid<T>, InputError>
+    where
+        T: Clone,
+    {
+        let width = input.lines().map(str::len).max().unwrap_or(0);
+        let mut cells = Vec::with_capacity(input.len());
+        for line in input.lines() {
+            push_cells(&mut cells, input, line, expected, &mut cell)?;
+            cells.resize(cells.len() + width - line.len(), fill.clone());
+        }
+
+        Ok(Grid::from_cells(width, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    #[inline]
+    fn index_of<C: TryInto<usize>>(&self, point: Point2<C>) -> Option<usize> {
+        let x = point.x.try_into().ok()?;
+        let y = point.y.try_into().ok()?;
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+
+    #[inline]
+    pub fn contains<C: TryInto<usize>>(&self, point: Point2<C>) -> bool {
+        self.index_of(point).is_some()
+    }
+
+    #[inline]
+    pub fn get<C: TryInto<usize>>(&self, point: Point2<C>) -> Option<&T> {
+        self.index_of(point).map(|i| &self.cells[i])
+    }
+
+    #[inline]
+    pub fn get_mut<C: TryInto<usize>>(&mut self, point: Point2<C>) -> Option<&mut T> {
+        self.index_of(point).map(|i| &mut self.cells[i])
+    }
+
+    /// The cell at `point` on a grid that repeats forever in every direction.
+    ///
+    /// # Panics
+    ///
+    /// If the grid is empty.
+    #[inline]
+    pub fn get_wrapping(&self, point: Point2<i64>) -> &T {
+        let x = point.x.rem_euclid(self.width as i64) as usize;
+        let y = point.y.rem_euclid(self.height as i64) as usize;
+        &self.cells[y * self.width + x]
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        // `chunks_exact` can't take a width of 0, but then there are no cells to chunk anyway
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    /// The cells of column `x`, from the top.
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(
+            x < self.width,
+            "column {x} is outside a grid {} wide",
+            self.width
+        );
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl DoubleEndedIterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Every cell along with its position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (Point2<usize>, &T)> {
+        let width = self.width;
+        (self.cells.iter().enumerate()).map(move |(i, cell)| (point_of(i, width), cell))
+    }
+
+    /// Where the first cell that matches is, reading row by row.
+    pub fn position(&self, matches: impl FnMut(&T) -> bool) -> Option<Point2<usize>> {
+        let i = self.cells.iter().position(matches)?;
+        Some(point_of(i, self.width))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::from_cells(self.width, self.cells.iter().map(f).collect())
+    }
+
+    /// The grid flipped over its leading diagonal, so rows become columns.
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let mut cells = Vec::with_capacity(self.cells.len());
+        for x in 0..self.width {
+            cells.extend(self.rows().map(|row| row[x].clone()));
+        }
+        Grid::from_cells(self.height, cells)
+    }
+
+    /// A window onto the part of the grid `width` by `height` from `origin`.
+    ///
+    /// # Panics
+    ///
+    /// If the window doesn't fit inside the grid.
+    pub fn view(&self, origin: Point2<usize>, width: usize, height: usize) -> GridView<'_, T> {
+        assert!(
+            origin.x + width <= self.width && origin.y + height <= self.height,
+            "a {width}x{height} view at {origin:?} doesn't fit in a {}x{} grid",
+            self.width,
+            self.height,
+        );
+        GridView {
+            grid: self,
+            origin,
+            width,
+            height,
+        }
+    }
+}
+
+fn push_cells<T>(
+    cells: &mut Vec<T>,
+    input: &str,
+    line: &str,
+    expected: &str,
+    cell: &mut impl FnMut(u8) -> Option<T>,
+) -> Result<(), InputError> {
+    let before = cells.len();
+    cells.extend(line.bytes().map_while(cell));
+    match cells.len() - before {
+        read if read == line.len() => Ok(()),
+        read => Err(InputError::within(
+            input,
+            &line.as_bytes()[read..],
+            expected,
+        )),
+    }
+}
+
+#[inline]
+fn point_of(i: usize, width: usize) -> Point2<usize> {
+    Point2::new(i % width, i / width)
+}
+
+#[cold]
+#[inline(never)]
+fn outside(point: impl Debug, width: usize, height: usize) -> ! {
+    panic!("{point:?} is outside a {width}x{height} grid")
+}
+
+impl<T, C: TryInto<usize> + Copy + Debug> Index<Point2<C>> for Grid<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, point: Point2<C>) -> &T {
+        match self.index_of(point) {
+            Some(i) => &self.cells[i],
+            None => outside(point, self.width, self.height),
+        }
+    }
+}
+
+impl<T, C: TryInto<usize> + Copy + Debug> IndexMut<Point2<C>> for Grid<T> {
+    #[inline]
+    fn index_mut(&mut self, point: Point2<C>) -> &mut T {
+        match self.index_of(point) {
+            Some(i) => &mut self.cells[i],
+            None => outside(point, self.width, self.height),
+        }
+    }
+}
+
+/// A borrowed rectangle inside a [`Grid`], with positions relative to its own top left.
+#[derive(Debug, Copy, Clone)]
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    origin: Point2<usize>,
+    width: usize,
+    height: usize,
+}
+
+impl<'a, T> GridView<'a, T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get<C: TryInto<usize>>(&self, point: Point2<C>) -> Option<&'a T> {
+        let x = point.x.try_into().ok()?;
+        let y = point.y.try_into().ok()?;
+        if x < self.width && y < self.height {
+            self.grid
+                .get(Point2::new(self.origin.x + x, self.origin.y + y))
+        } else {
+            None
+        }
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &'a [T]> + ExactSizeIterator {
+        let (grid, origin, width) = (self.grid, self.origin, self.width);
+        (origin.y..origin.y + self.height).map(move |y| &grid.row(y)[origin.x..origin.x + width])
+    }
+
+    /// Copies the view out into a grid of its own.
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = self.rows().flatten().cloned().collect();
+        Grid::from_cells(self.width, cells)
+    }
+}
+
+fn write_rows<'a, T: Display + 'a>(
+    f: &mut Formatter<'_>,
+    rows: impl Iterator<Item = &'a [T]>,
+) -> std::fmt::Result {
+    for row in rows {
+        row.iter().try_for_each(|cell| write!(f, "{cell}"))?;
+        writeln!(f)?;
+    }
+    Ok(())
+}
+
+/// Each row on a line of its own, with the cells written next to each other.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write_rows(f, self.rows())
+    }
+}
+
+impl<T: Display> Display for GridView<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write_rows(f, self.rows())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Grid;
+    use crate::geom::Point2;
+
+    fn digits(input: &str) -> Grid<u8> {
+        Grid::parse(input, "a digit", |b| b.is_ascii_digit().then(|| b - b'0')).unwrap()
+    }
+
+    #[test]
+    fn grids_parse_and_render() {
+        let grid = digits("123\n456\n");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.to_string(), "123\n456\n");
+        assert_eq!(grid.transpose().to_string(), "14\n25\n36\n");
+        assert_eq!(grid.view(Point2::new(1, 0), 2, 2).to_string(), "23\n56\n");
+        assert_eq!(grid.view(Point2::new(1, 1), 2, 1).to_grid(), digits("56\n"));
+
+        let err = Grid::parse("12\n3x\n", "a digit", |b| b.is_ascii_digit().then_some(b));
+        let err = err.unwrap_err();
+        assert_eq!((err.line, err.column, err.found.as_str()), (2, 2, "'x'"));
+        let err = Grid::parse("12\n3\n", "a digit", |b| b.is_ascii_digit().then_some(b));
+        assert_eq!(err.unwrap_err().expected, "a row 2 wide");
+
+        let padded = Grid::parse_padded(" 1\n2\n", b' ', "a digit or space", Some).unwrap();
+        assert_eq!(padded.map(|&b| b as char).to_string(), " 1\n2 \n");
+    }
+
+    #[test]
+    fn cells_are_found_by_position() {
+        let mut grid = digits("123\n456\n");
+        assert_eq!(grid.get(Point2::new(2_i64, 1)), Some(&6));
+        assert_eq!(grid.get(Point2::new(-1_i64, 1)), None);
+        assert_eq!(grid.get(Point2::new(3_usize, 0)), None);
+        assert_eq!(*grid.get_wrapping(Point2::new(-1, 3)), 6);
+        assert_eq!(grid.position(|&d| d == 5), Some(Point2::new(1, 1)));
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), [2, 5]);
+        assert_eq!(grid.rows().next_back(), Some(&[4, 5, 6][..]));
+
+        grid[Point2::new(0, 1)] = 9;
+        *grid.get_mut(Point2::new(0_i32, 0)).unwrap() = 0;
+        assert_eq!(grid.to_string(), "023\n956\n");
+        let evens = grid.positions().filter(|(_, &d)| d % 2 == 0);
+        assert_eq!(evens.count(), 3);
+    }
+}
@@ -0,0 +1,209 @@
+//! A grid without edges, for simulations that spread out as far as their input takes them.
+//!
+//! The grid keeps every cell of a box laid out row by row, like a dense grid, so finding a cell
+//! is the same few sums. Writing outside the box grows it by whole chunks, square (or cubic)
+//! blocks of cells, at least doubling it along the way so that growing stays rare. Every cell
+//! starts out as the grid's fill value, and coordinates can be anything, negative included,
+//! though cells spread far apart make for a big box.
+
+use std::fmt::{Debug, Formatter};
+use std::ops::{Index, IndexMut};
+
+/// An unbounded grid of `D` dimensions. Positions are `[i64; D]`, or anything that converts to
+/// one, such as a [`Point2`](crate::geom::Point2).
+pub struct InfiniteGrid<T, const D: usize> {
+    fill: T,
+    // the box `size` cells across from `origin`, with the first axis changing fastest
+    cells: Vec<T>,
+    origin: [i64; D],
+    size: [usize; D],
+}
+
+pub type InfiniteGrid2<T> = InfiniteGrid<T, 2>;
+pub type InfiniteGrid3<T> = InfiniteGrid<T, 3>;
+
+impl<T: Clone, const D: usize> InfiniteGrid<T, D> {
+    /// Each side of a chunk is `1 << BITS` cells long.
+    const BITS: u32 = if D > 2 { 3 } else { 5 };
+
+    /// A grid where every cell is `fill`.
+    pub fn new(fill: T) -> InfiniteGrid<T, D> {
+        InfiniteGrid {
+            fill,
+            cells: Vec::new(),
+            origin: [0; D],
+            size: [0; D],
+        }
+    }
+
+    /// Where `at` is in `cells`, if it's inside the box.
+    #[inline]
+    fn index_of(&self, at: [i64; D]) -> Option<usize> {
+        index_in(at, self.origin, self.size)
+    }
+
+    /// The position of the cell at `index` in `cells`.
+    fn position(&self, index: usize) -> [i64; D] {
+        let mut rest = index;
+        std::array::from_fn(|axis| {
+            let i = rest % self.size[axis];
+            rest /= self.size[axis];
+            self.origin[axis] + i as i64
+        })
+    }
+
+    #[inline]
+    pub fn get(&self, at: impl Into<[i64; D]>) -> &T {
+        match self.index_of(at.into()) {
+            Some(index) => &self.cells[index],
+            None => &self.fill,
+        }
+    }
+
+    /// The cell at `at`, making room for it if it's outside everything written so far.
+    #[inline]
+    pub fn get_mut(&mut self, at: impl Into<[i64; D]>) -> &mut T {
+        let at = at.into();
+        let index = match self.index_of(at) {
+            Some(index) => index,
+            None => self.grow_to(at),
+        };
+        &mut self.cells[index]
+    }
+
+    #[inline]
+    pub fn set(&mut self, at: impl Into<[i64; D]>, value: T) {
+        *self.get_mut(at) = value;
+    }
+
+    /// Makes the box big enough to hold `at`, and says where it is.
+    #[cold]
+    fn grow_to(&mut self, at: [i64; D]) -> usize {
+        let chunk = 1 << Self::BITS;
+        // the box always starts and ends on the edge of a chunk
+        let key = at.map(|coord| coord >> Self::BITS << Self::BITS);
+        let (mut origin, mut size) = (key, [chunk; D]);
+        if !self.cells.is_empty() {
+            for axis in 0..D {
+                let (start, len) = (self.origin[axis], self.size[axis] as i64);
+                let end = start + len;
+                // growing by at least as much as there is already keeps this rare
+                let (start, end) = match key[axis] {
+                    k if k < start => (k.min(start - len), end),
+                    k if k >= end => (start, (k + chunk as i64).max(end + len)),
+                    _ => (start, end),
+                };
+                origin[axis] = start;
+                size[axis] = (end - start) as usize;
+            }
+        }
+
+        let mut cells = vec![self.fill.clone(); size.iter().product()];
+        // the old box goes in a row at a time, as its rows stay whole. an empty one has no rows
+        let row_len = self.size[0];
+        for (row, old) in self.cells.chunks_exact(row_len.max(1)).enumerate() {
+            let first = self.position(row * row_len);
+            let new = index_in(first, origin, size).expect("the box only grows");
+            cells[new..new + row_len].clone_from_slice(old);
+        }
+        (self.cells, self.origin, self.size) = (cells, origin, size);
+
+        self.index_of(at)
+            .expect("the box was grown to fit the cell")
+    }
+
+    /// Sets every cell back to the fill value, keeping the room made for them.
+    pub fn clear(&mut self) {
+        self.cells.fill(self.fill.clone());
+    }
+
+    /// Every cell that's been made room for, which includes cells near the ones written to that
+    /// are still the fill value. They come in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = ([i64; D], &T)> {
+        let cells = self.cells.iter().enumerate();
+        cells.map(move |(index, cell)| (self.position(index), cell))
+    }
+}
+
+/// Where `at` goes in a box `size` cells across from `origin`, if it fits.
+#[inline]
+fn index_in<const D: usize>(at: [i64; D], origin: [i64; D], size: [usize; D]) -> Option<usize> {
+    let (mut index, mut stride, mut outside) = (0, 1, false);
+    for axis in 0..D {
+        // anything before the origin wraps around to far past the end
+        let i = at[axis].wrapping_sub(origin[axis]) as usize;
+        // one branch for all the axes at the end is quicker than one for each of them
+        outside |= i >= size[axis];
+        index = i.wrapping_mul(stride).wrapping_add(index);
+        stride *= size[axis];
+    }
+    (!outside).then_some(index)
+}
+
+impl<T: Clone, const D: usize, P: Into<[i64; D]>> Index<P> for InfiniteGrid<T, D> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, at: P) -> &T {
+        self.get(at)
+    }
+}
+
+impl<T: Clone, const D: usize, P: Into<[i64; D]>> IndexMut<P> for InfiniteGrid<T, D> {
+    #[inline]
+    fn index_mut(&mut self, at: P) -> &mut T {
+        self.get_mut(at)
+    }
+}
+
+impl<T: Clone, const D: usize> Debug for InfiniteGrid<T, D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InfiniteGrid")
+            .field("origin", &self.origin)
+            .field("size", &self.size)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InfiniteGrid2, InfiniteGrid3};
+    use crate::geom::Point2;
+
+    #[test]
+    fn cells_can_be_anywhere() {
+        let mut grid = InfiniteGrid2::new(0);
+        let far = [-1_007, 3_019];
+        grid[Point2::new(-1_i64, -1)] = 1;
+        grid[Point2::new(0_i32, 0)] = 2;
+        grid.set(far, 3);
+        *grid.get_mut([31, 32]) += 4;
+
+        assert_eq!(grid[[-1, -1]], 1);
+        assert_eq!(grid[[0, 0]], 2);
+        assert_eq!(grid[far], 3);
+        assert_eq!(grid[[31, 32]], 4);
+        assert_eq!(grid[[32, 31]], 0);
+        assert_eq!(grid[[-5_000, 12]], 0);
+        assert_eq!(grid[[5_000, -12]], 0);
+
+        let mut set = grid.iter().filter(|(_, &v)| v != 0).collect::<Vec<_>>();
+        set.sort();
+        assert_eq!(
+            set,
+            [(far, &3), ([-1, -1], &1), ([0, 0], &2), ([31, 32], &4)]
+        );
+
+        grid.clear();
+        assert!(grid.iter().all(|(_, &v)| v == 0));
+    }
+
+    #[test]
+    fn grids_can_have_depth() {
+        let mut grid = InfiniteGrid3::new(false);
+        grid[[1, -2, 3]] = true;
+        assert!(grid[[1, -2, 3]]);
+        assert!(!grid[[1, -2, -3]]);
+        assert!(!grid[[3, -2, 1]]);
+    }
+}
@@ -0,0 +1,326 @@
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+use std::io::{ErrorKind, Read};
+use std::path::PathBuf;
+
+use anyhow::Context;
+use itertools::Itertools;
+use thiserror::Error;
+
+use crate::{DayEntry, DayId};
+
+/// Where a day's puzzle input is read from at runtime.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum InputSource {
+    /// `<dir>/<year>/real/NN.txt` or `<dir>/<year>/test/NN.txt`, falling back to the embedded input if the file
+    /// doesn't exist
+    Dir(PathBuf),
+    /// the same file for every selected day
+    File(PathBuf),
+    Stdin,
+    /// only the inputs baked into the binary
+    Embedded,
+}
+
+impl Default for InputSource {
+    fn default() -> Self {
+        InputSource::Dir(PathBuf::from("input"))
+    }
+}
+
+impl InputSource {
+    /// Whether this source can only supply the input for a single day.
+    pub fn is_single(&self) -> bool {
+        matches!(self, InputSource::File(_) | InputSource::Stdin)
+    }
+
+    pub fn load(
+        &self,
+        id: DayId,
+        entry: &DayEntry,
+        is_test: bool,
+    ) -> anyhow::Result<Cow<'static, str>> {
+        match self {
+            InputSource::Dir(dir) => {
+                let kind = if is_test { "test" } else { "real" };
+                let path = dir
+                    .join(id.year.to_string())
+                    .join(kind)
+                    .join(format!("{:02}.txt", id.day));
+                match std::fs::read_to_string(&path) {
+                    Ok(input) => Ok(Cow::Owned(input)),
+                    Err(err) if err.kind() == ErrorKind::NotFound => {
+                        entry.embedded(is_test).map(Cow::Borrowed).with_context(|| {
+                            format!(
+                                "no input at {} and no embedded {kind} input for {id}",
+                                path.display()
+                            )
+                        })
+                    }
+                    Err(err) => {
+                        Err(err).with_context(|| format!("failed to read {}", path.display()))
+                    }
+                }
+            }
+            InputSource::File(path) => std::fs::read_to_string(path)
+                .map(Cow::Owned)
+                .with_context(|| format!("failed to read {}", path.display())),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                std::io::stdin()
+                    .read_to_string(&mut input)
+                    .context("failed to read input from stdin")?;
+                Ok(Cow::Owned(input))
+            }
+            InputSource::Embedded => {
+                entry.embedded(is_test).map(Cow::Borrowed).with_context(|| {
+                    let kind = if is_test { "test" } else { "real" };
+                    format!("no embedded {kind} input for {id}")
+                })
+            }
+        }
+    }
+}
+
+/// Something [`normalise`] changed to put an input in the form the days expect.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Fix {
+    ByteOrderMark,
+    /// this many lines ended with `\r\n`
+    CrLf(usize),
+    /// this many lines ended with spaces or tabs
+    TrailingWhitespace(usize),
+    /// this many blank lines were at the end
+    TrailingBlankLines(usize),
+    FinalNewline,
+}
+
+impl Display for Fix {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Fix::ByteOrderMark => write!(f, "dropped a byte order mark"),
+            Fix::CrLf(lines) => write!(f, "changed {lines} \\r\\n line endings to \\n"),
+            Fix::TrailingWhitespace(lines) => {
+                write!(f, "trimmed whitespace from the end of {lines} lines")
+            }
+            Fix::TrailingBlankLines(lines) => write!(f, "dropped {lines} blank lines at the end"),
+            Fix::FinalNewline => write!(f, "added a newline at the end"),
+        }
+    }
+}
+
+/// An input that `--strict-input` refused to tidy up.
+#[derive(Debug, Error, Clone, Eq, PartialEq)]
+#[error("the input isn't canonical, normalising it would have {}", .0.iter().join(", "))]
+pub struct Untidy(pub Vec<Fix>);
+
+/// Puts `input` in the form every day's parser expects: `\n` line endings, no spaces or tabs at
+/// the end of a line, and a single newline at the end. Borrows `input` if that's already its form.
+/// Returns what had to change too.
+pub fn normalise(input: &str) -> (Cow<'_, str>, Vec<Fix>) {
+    let is_canonical = !input.starts_with('\u{feff}')
+        && !input.contains('\r')
+        && !input.contains(" \n")
+        && !input.contains("\t\n")
+        && (input.is_empty() || input.ends_with('\n') && !input.ends_with("\n\n"));
+    if is_canonical {
+        return (Cow::Borrowed(input), Vec::new());
+    }
+
+    let mut fixes = Vec::new();
+    let body = match input.strip_prefix('\u{feff}') {
+        Some(body) => {
+            fixes.push(Fix::ByteOrderMark);
+            body
+        }
+        None => input,
+    };
+
+    let mut tidied = String::with_capacity(body.len() + 1);
+    let (mut crlf, mut trimmed) = (0, 0);
+    for line in body.split_inclusive('\n') {
+        let line = line.strip_suffix('\n').unwrap_or(line);
+        let line = line.strip_suffix('\r').map_or(line, |line| {
+            crlf += 1;
+            line
+        });
+        let kept = line.trim_end_matches([' ', '\t']);
+        if kept.len() != line.len() {
+            trimmed += 1;
+        }
+        tidied.push_str(kept);
+        tidied.push('\n');
+    }
+
+    let content = tidied.trim_end_matches('\n').len();
+    let blank = tidied.len() - content - usize::from(content > 0);
+    tidied.truncate(content);
+    if content > 0 {
+        tidied.push('\n');
+    }
+
+    if crlf > 0 {
+        fixes.push(Fix::CrLf(crlf));
+    }
+    if trimmed > 0 {
+        fixes.push(Fix::TrailingWhitespace(trimmed));
+    }
+    if blank > 0 {
+        fixes.push(Fix::TrailingBlankLines(blank));
+    }
+    if !body.is_empty() && !body.ends_with('\n') {
+        fixes.push(Fix::FinalNewline);
+    }
+
+    (Cow::Owned(tidied), fixes)
+}
+
+/// A puzzle input that isn't shaped the way a day expects, pointing at where it stops making sense.
+/// Lines and columns count from 1, and columns are in bytes.
+#[derive(Debug, Error, Clone, Eq, PartialEq)]
+pub struct InputError {
+    /// filled in by [`run_day`](crate::run_day), as days don't know which day they are
+    pub day: Option<DayId>,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl InputError {
+    /// An error at byte `offset` of `input`, having found whatever is there.
+    pub fn at(input: impl AsRef<[u8]>, offset: usize, expected: impl Into<String>) -> InputError {
+        let input = input.as_ref();
+        let offset = offset.min(input.len());
+        let line_start = input[..offset]
+            .iter()
+            .rposition(|&b| b == b'\n')
+            .map_or(0, |i| i + 1);
+        let found = match input[offset..].first() {
+            None => "end of input".to_string(),
+            Some(b'\n' | b'\r') => "end of line".to_string(),
+            Some(&b) => match std::str::from_utf8(&input[offset..]) {
+                Ok(rest) => format!("{:?}", rest.chars().next().unwrap_or_default()),
+                Err(_) => format!("{:?}", b as char),
+            },
+        };
+
+        InputError {
+            day: None,
+            line: input[..offset].iter().filter(|&&b| b == b'\n').count() + 1,
+            column: offset - line_start + 1,
+            expected: expected.into(),
+            found,
+        }
+    }
+
+    /// An error at the start of `rest`, which is a part of `input`, such as a line of it or what
+    /// a parser couldn't consume.
+    pub fn within(
+        input: impl AsRef<[u8]>,
+        rest: impl AsRef<[u8]>,
+        expected: impl Into<String>,
+    ) -> InputError {
+        let input = input.as_ref();
+        let offset = (rest.as_ref().as_ptr() as usize).saturating_sub(input.as_ptr() as usize);
+        InputError::at(input, offset, expected)
+    }
+
+    /// The error for a nom parser that failed somewhere in `input`.
+    pub fn nom<I: AsRef<[u8]>>(
+        input: I,
+        err: nom::Err<nom::error::Error<I>>,
+        expected: impl Into<String>,
+    ) -> InputError {
+        match err {
+            nom::Err::Error(err) | nom::Err::Failure(err) => {
+                InputError::within(input, err.input, expected)
+            }
+            nom::Err::Incomplete(_) => {
+                let len = input.as_ref().len();
+                InputError::at(input, len, expected)
+            }
+        }
+    }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {day}, ")?;
+        }
+        write!(
+            f,
+            "line {}, column {}: expected {}, found {}",
+            self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+/// Tells an [`InputError`] in `err` which day it came from.
+pub(crate) fn for_day(mut err: anyhow::Error, day: DayId) -> anyhow::Error {
+    if let Some(input_err) = err.downcast_mut::<InputError>() {
+        input_err.day.get_or_insert(day);
+    }
+    err
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{normalise, Fix, InputError};
+    use crate::DayId;
+    use std::borrow::Cow;
+
+    #[test]
+    fn inputs_are_normalised() {
+        let tidy = "1000\n2000\n\n3000\n";
+        assert_eq!(normalise(tidy), (Cow::Borrowed(tidy), vec![]));
+        assert_eq!(normalise(""), (Cow::Borrowed(""), vec![]));
+
+        let (input, fixes) = normalise("\u{feff}1000\r\n2000 \t\r\n\r\n3000");
+        assert_eq!(input, "1000\n2000\n\n3000\n");
+        assert_eq!(
+            fixes,
+            vec![
+                Fix::ByteOrderMark,
+                Fix::CrLf(3),
+                Fix::TrailingWhitespace(1),
+                Fix::FinalNewline
+            ]
+        );
+
+        let (input, fixes) = normalise("    [D]    \n[N] [C]\n\n\n");
+        assert_eq!(input, "    [D]\n[N] [C]\n");
+        assert_eq!(
+            fixes,
+            vec![Fix::TrailingWhitespace(1), Fix::TrailingBlankLines(2)]
+        );
+        assert_eq!(normalise("\n\n").0, "");
+    }
+
+    #[test]
+    fn errors_point_at_the_problem() {
+        let input = "1-2,3-4\n5-6,7x8\n";
+        let err = InputError::at(input, 13, "a digit");
+        assert_eq!((err.line, err.column), (2, 6));
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 6: expected a digit, found 'x'"
+        );
+
+        let line = input.lines().nth(1).unwrap();
+        assert_eq!(
+            InputError::within(input, &line[7..], "a comma").found,
+            "end of line"
+        );
+        assert_eq!(InputError::at(input, 99, "a digit").found, "end of input");
+
+        let err = nom::bytes::complete::tag::<_, _, nom::error::Error<_>>("5-7")(&input[8..]);
+        let mut err = InputError::nom(input, err.unwrap_err(), "a range");
+        err.day = Some(DayId::new(2022, 4));
+        assert_eq!(
+            err.to_string(),
+            "day 2022/4, line 2, column 1: expected a range, found '5'"
+        );
+    }
+}
@@ -1,29 +1,80 @@
 extern crate core;
 
+pub mod answers;
+pub mod cycle;
 pub mod days;
+pub mod geom;
+pub mod grid;
+pub mod infinite;
+pub mod input;
+pub mod options;
+pub mod output;
+pub mod params;
+pub mod pool;
+pub mod scaffold;
+pub mod search;
+pub mod serialise;
+pub mod timeout;
+pub mod timing;
 
+use std::collections::BTreeMap;
 use std::fmt::{Display, Formatter};
-use std::time::Instant;
+use std::panic::AssertUnwindSafe;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
 
-use nom::combinator::opt;
 use nom::{
     branch::alt,
     bytes::complete::tag,
     character::complete as character,
-    combinator::{all_consuming, map},
-    sequence::{pair, preceded},
+    combinator::{all_consuming, cut, map, opt},
+    multi::separated_list1,
+    sequence::{pair, preceded, terminated, tuple},
 };
+use num::bigint::{BigInt, BigUint, ToBigInt};
 use thiserror::Error;
 
+use crate::params::{DayParams, Param};
+use crate::serialise::SerialiseError;
+use crate::timing::{Repeat, Stats};
+
 macro_rules! impl_answer_enum {
-    ( $( ($variant:tt, $ty:ty) ),* ) => {
+    (
+        integers: $( ($int_variant:tt, $int_ty:ty) ),*;
+        others: $( ($variant:tt, $ty:ty) ),*;
+        structured: $( ($structured_variant:tt, $structured_ty:ty) ),*
+    ) => {
+        /// The answer to one part of a day.
+        ///
+        /// Every answer has a canonical string form, which is what it displays as and what the
+        /// answer sheets store:
+        /// - integers of any width are written in decimal, with a leading `-` if negative
+        /// - strings are written as they are
+        /// - lists are their items' canonical forms joined by `,`
+        /// - grids are their rows, each followed by a newline
+        ///
+        /// Answers compare by kind and value, so `U32(5) == I64(5)` but `U32(5) != String("5")`.
+        /// [`Answers::matches`] can also insist on the same variant.
         #[derive(Debug)]
         pub enum Answers {
+            $(
+                $int_variant($int_ty),
+            )*
             $(
                 $variant($ty),
             )*
+            $(
+                $structured_variant($structured_ty),
+            )*
         }
 
+        $(
+            impl From<$int_ty> for Answers {
+                fn from(t: $int_ty) -> Self {
+                    Answers::$int_variant(t)
+                }
+            }
+        )*
         $(
             impl From<$ty> for Answers {
                 fn from(t: $ty) -> Self {
@@ -31,51 +82,179 @@ macro_rules! impl_answer_enum {
                 }
             }
         )*
+        $(
+            impl From<$structured_ty> for Answers {
+                fn from(t: $structured_ty) -> Self {
+                    Answers::$structured_variant(t)
+                }
+            }
+        )*
 
-        // assumes all types impl Display
-        impl Display for Answers {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        impl Answers {
+            /// The name of the variant, e.g. `U32` for `Answers::U32(_)`.
+            pub fn variant_name(&self) -> &'static str {
                 match self {
                     $(
-                        Answers::$variant(t) => write!(f, "{t}"),
+                        Answers::$int_variant(_) => stringify!($int_variant),
+                    )*
+                    $(
+                        Answers::$variant(_) => stringify!($variant),
+                    )*
+                    $(
+                        Answers::$structured_variant(_) => stringify!($structured_variant),
                     )*
                 }
             }
-        }
 
-        impl Eq for Answers {}
+            /// Reads back an answer written in its canonical form, given the name of its variant.
+            /// Structured answers are read by [`serialise`].
+            pub(crate) fn from_canonical(variant: &str, value: &str) -> Result<Answers, SerialiseError> {
+                let invalid = || SerialiseError::InvalidValue {
+                    variant: variant.to_string(),
+                    value: value.to_string(),
+                };
+                match variant {
+                    $(
+                        stringify!($int_variant) => value.parse().map(Answers::$int_variant).map_err(|_| invalid()),
+                    )*
+                    $(
+                        stringify!($variant) => value.parse().map(Answers::$variant).map_err(|_| invalid()),
+                    )*
+                    _ => Err(SerialiseError::UnknownType(variant.to_string())),
+                }
+            }
 
-        impl PartialEq for Answers {
-            fn eq(&self, other: &Self) -> bool {
-                let val_self = match self {
+            /// The value of an integer answer, whatever its width.
+            pub fn as_integer(&self) -> Option<BigInt> {
+                match self {
                     $(
-                    Answers::$variant(v) => format!("{v}"),
+                        Answers::$int_variant(n) => n.to_bigint(),
                     )*
-                };
-                let val_other = match other {
+                    _ => None,
+                }
+            }
+        }
+
+        // assumes all types but the structured ones impl Display
+        impl Display for Answers {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
                     $(
-                    Answers::$variant(v) => format!("{v}"),
+                        Answers::$int_variant(t) => write!(f, "{t}"),
                     )*
-                };
-                val_self == val_other
+                    $(
+                        Answers::$variant(t) => write!(f, "{t}"),
+                    )*
+                    $(
+                        Answers::$structured_variant(_) => self.write_structured(f),
+                    )*
+                }
             }
         }
     }
 }
 
 impl_answer_enum! {
-    (String, String),
-    (Usize, usize),
-    (U64, u64),
-    (U32, u32),
-    (U16, u16),
-    (U8, u8),
-    (Isize, isize),
-    (I64, i64),
-    (I32, i32),
-    (I16, i16),
-    (I8, i8),
-    (Day10Result, Day10Result)
+    integers:
+        (Usize, usize),
+        (U128, u128),
+        (U64, u64),
+        (U32, u32),
+        (U16, u16),
+        (U8, u8),
+        (Isize, isize),
+        (I128, i128),
+        (I64, i64),
+        (I32, i32),
+        (I16, i16),
+        (I8, i8),
+        (BigInt, BigInt);
+    others:
+        (String, String),
+        (Day10Result, Day10Result);
+    structured:
+        (List, Vec<Answers>),
+        (Grid, Vec<String>)
+}
+
+/// What an answer is, regardless of how it's stored.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum AnswerKind {
+    Integer,
+    Text,
+    List,
+    Grid,
+}
+
+impl Answers {
+    /// A list answer, e.g. the order things happened in.
+    pub fn list<T: Into<Answers>>(items: impl IntoIterator<Item = T>) -> Answers {
+        Answers::List(items.into_iter().map(Into::into).collect())
+    }
+
+    /// A grid answer, given row by row.
+    pub fn grid<R: Into<String>>(rows: impl IntoIterator<Item = R>) -> Answers {
+        Answers::Grid(rows.into_iter().map(Into::into).collect())
+    }
+
+    fn write_structured(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answers::List(items) => {
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                Ok(())
+            }
+            Answers::Grid(rows) => rows.iter().try_for_each(|row| writeln!(f, "{row}")),
+            _ => unreachable!("only called for structured answers"),
+        }
+    }
+
+    fn kind(&self) -> AnswerKind {
+        match self {
+            _ if self.as_integer().is_some() => AnswerKind::Integer,
+            Answers::List(_) => AnswerKind::List,
+            Answers::Grid(_) | Answers::Day10Result(_) => AnswerKind::Grid,
+            _ => AnswerKind::Text,
+        }
+    }
+
+    /// Whether two answers are the same. Integers are compared by value and everything else by
+    /// its canonical form, but only against answers of the same kind. If `strict`, the variants
+    /// have to match too, so `U32(5)` doesn't match `U64(5)`.
+    pub fn matches(&self, other: &Answers, strict: bool) -> bool {
+        if self.kind() != other.kind() || (strict && self.variant_name() != other.variant_name()) {
+            return false;
+        }
+        match (self, other) {
+            (Answers::List(ours), Answers::List(theirs)) => {
+                ours.len() == theirs.len()
+                    && ours
+                        .iter()
+                        .zip(theirs)
+                        .all(|(ours, theirs)| ours.matches(theirs, strict))
+            }
+            _ if self.kind() == AnswerKind::Integer => self.as_integer() == other.as_integer(),
+            _ => self.to_string() == other.to_string(),
+        }
+    }
+}
+
+impl Eq for Answers {}
+
+impl PartialEq for Answers {
+    fn eq(&self, other: &Self) -> bool {
+        self.matches(other, false)
+    }
+}
+
+impl From<BigUint> for Answers {
+    fn from(n: BigUint) -> Self {
+        Answers::BigInt(n.into())
+    }
 }
 
 #[derive(Debug)]
@@ -93,6 +272,32 @@ impl Display for Day10Result {
     }
 }
 
+impl FromStr for Day10Result {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut result = Day10Result([0; 6]);
+        let mut lines = s.lines();
+        for row in &mut result.0 {
+            let line = lines.next().ok_or(())?;
+            if line.chars().count() != 40 {
+                return Err(());
+            }
+            for (i, c) in line.chars().enumerate() {
+                match c {
+                    '#' => *row |= 1 << i,
+                    ' ' => {}
+                    _ => return Err(()),
+                }
+            }
+        }
+        match lines.next() {
+            Some(_) => Err(()),
+            None => Ok(result),
+        }
+    }
+}
+
 impl From<&'_ str> for Answers {
     fn from(s: &'_ str) -> Self {
         Answers::String(s.to_string())
@@ -138,65 +343,381 @@ where
     }
 }
 
+/// The answer to a single part, from a phased [`Day`].
+pub trait IntoAnswer {
+    fn into_answer(self) -> anyhow::Result<Option<Answers>>;
+}
+
+impl IntoAnswer for () {
+    fn into_answer(self) -> anyhow::Result<Option<Answers>> {
+        Ok(None)
+    }
+}
+
+impl<A> IntoAnswer for A
+where
+    A: Into<Answers>,
+{
+    fn into_answer(self) -> anyhow::Result<Option<Answers>> {
+        Ok(Some(self.into()))
+    }
+}
+
+/// A day split into a parse phase and two parts that only share the parsed input, so each phase
+/// can be timed on its own.
+pub trait Day {
+    type Parsed<'a>;
+
+    fn parse<'a>(input: &'a str, params: &DayParams) -> anyhow::Result<Self::Parsed<'a>>;
+
+    fn part1(parsed: &Self::Parsed<'_>, params: &DayParams) -> anyhow::Result<Option<Answers>>;
+
+    fn part2(parsed: &Self::Parsed<'_>, params: &DayParams) -> anyhow::Result<Option<Answers>>;
+
+    fn solve(input: &str, params: &DayParams) -> anyhow::Result<DayResult> {
+        let (input, _) = input::normalise(input);
+        let parsed = Self::parse(&input, params)?;
+        Ok(DayResult {
+            part1: Self::part1(&parsed, params)?,
+            part2: Self::part2(&parsed, params)?,
+        })
+    }
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct DayResult {
     pub part1: Option<Answers>,
     pub part2: Option<Answers>,
 }
 
-pub struct DayEntry {
-    pub f: fn(&'static str, bool) -> anyhow::Result<DayResult>,
-    pub real: &'static str,
-    pub test: &'static str,
+impl DayResult {
+    /// Drops the answers to any parts that weren't asked for.
+    pub fn only(self, parts: Parts) -> DayResult {
+        DayResult {
+            part1: self.part1.filter(|_| parts.has_part1()),
+            part2: self.part2.filter(|_| parts.has_part2()),
+        }
+    }
 }
 
-pub fn run_day(
-    day: u32,
-    DayEntry { f, real, test }: &DayEntry,
-    is_test: bool,
-) -> anyhow::Result<()> {
-    let input = if is_test { *test } else { *real };
+/// Which parts of a day to run.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub enum Parts {
+    #[default]
+    Both,
+    Part1,
+    Part2,
+}
+
+impl Parts {
+    pub fn has_part1(self) -> bool {
+        self != Parts::Part2
+    }
+
+    pub fn has_part2(self) -> bool {
+        self != Parts::Part1
+    }
+
+    pub fn union(self, other: Parts) -> Parts {
+        if self == other {
+            self
+        } else {
+            Parts::Both
+        }
+    }
+}
+
+/// How long each phase of a day took. Days that haven't been split into a [`Day`] do all their
+/// work in `parse`, and have no separate part timings.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct PhaseTimes {
+    pub parse: Duration,
+    pub part1: Option<Duration>,
+    pub part2: Option<Duration>,
+}
+
+pub type Solver = fn(&str, &DayParams, Parts) -> anyhow::Result<(DayResult, PhaseTimes)>;
+
+/// Runs the phases of `D` needed for `parts`, timing them separately.
+pub fn run_phases<D: Day>(
+    input: &str,
+    params: &DayParams,
+    parts: Parts,
+) -> anyhow::Result<(DayResult, PhaseTimes)> {
+    let start = Instant::now();
+    let parsed = D::parse(input, params)?;
+    let parse = start.elapsed();
 
+    let mut result = DayResult {
+        part1: None,
+        part2: None,
+    };
+    let mut phases = PhaseTimes {
+        parse,
+        ..Default::default()
+    };
+
+    if parts.has_part1() {
+        let start = Instant::now();
+        result.part1 = D::part1(&parsed, params)?;
+        phases.part1 = Some(start.elapsed());
+    }
+
+    if parts.has_part2() {
+        let start = Instant::now();
+        result.part2 = D::part2(&parsed, params)?;
+        phases.part2 = Some(start.elapsed());
+    }
+
+    Ok((result, phases))
+}
+
+/// Adapter for days that still solve everything in one `run` function. Both parts are always
+/// computed, but only the requested answers are kept.
+pub fn run_whole(
+    run: fn(&str, &DayParams) -> anyhow::Result<DayResult>,
+    input: &str,
+    params: &DayParams,
+    parts: Parts,
+) -> anyhow::Result<(DayResult, PhaseTimes)> {
     let start = Instant::now();
-    let answer = f(input, is_test)?;
-    let end = start.elapsed();
+    let result = run(input, params)?;
+    let parse = start.elapsed();
 
-    println!("day {}:", day);
+    Ok((
+        result.only(parts),
+        PhaseTimes {
+            parse,
+            ..Default::default()
+        },
+    ))
+}
 
-    if let Some(part1) = answer.part1 {
-        println!("part 1:");
-        let part1 = format!("{part1}");
-        for line in part1.lines() {
-            println!("\t{line}");
+#[derive(Copy, Clone)]
+pub struct DayEntry {
+    pub title: &'static str,
+    pub f: Solver,
+    pub params: &'static [Param],
+    pub real: Option<&'static str>,
+    pub test: Option<&'static str>,
+    /// the answers for the example input, if known
+    pub example: [Option<&'static str>; 2],
+    /// takes long enough that it's skipped unless asked for
+    pub slow: bool,
+}
+
+impl DayEntry {
+    /// The input compiled into the binary, if any.
+    pub fn embedded(&self, is_test: bool) -> Option<&'static str> {
+        if is_test {
+            self.test
+        } else {
+            self.real
         }
     }
+}
+
+/// The answers for one day along with how long they took to compute.
+#[derive(Debug)]
+pub struct DayRun {
+    pub day: DayId,
+    pub parts: Parts,
+    pub result: DayResult,
+    pub timing: Stats,
+    /// the median time of each phase
+    pub phases: PhaseTimes,
+}
+
+/// Runs `day`, normalising its input first. With `strict`, an input that needs normalising is an
+/// error instead, listing what would have changed.
+pub fn run_day(
+    day: DayId,
+    DayEntry { f, .. }: &DayEntry,
+    input: &str,
+    params: &DayParams,
+    parts: Parts,
+    repeat: Repeat,
+    strict: bool,
+) -> anyhow::Result<DayRun> {
+    let (input, fixes) = input::normalise(input);
+    if strict && !fixes.is_empty() {
+        return Err(input::Untidy(fixes).into());
+    }
+
+    let run = || f(&input, params, parts).map_err(|err| input::for_day(err, day));
+    for _ in 0..repeat.warmup {
+        run()?;
+    }
+
+    let runs = repeat.runs.max(1);
+    let mut samples = Vec::with_capacity(runs);
+    let mut phase_samples = Vec::with_capacity(runs);
+    let mut result = None;
+    for _ in 0..runs {
+        let start = Instant::now();
+        let (answer, phases) = run()?;
+        samples.push(start.elapsed());
+        phase_samples.push(phases);
+        result = Some(answer);
+    }
+
+    let median_of = |phase: fn(&PhaseTimes) -> Option<Duration>| {
+        let samples = phase_samples.iter().filter_map(phase).collect::<Vec<_>>();
+        Stats::from_samples(&samples).map(|stats| stats.median)
+    };
+    let phases = PhaseTimes {
+        parse: median_of(|p| Some(p.parse)).unwrap_or_default(),
+        part1: median_of(|p| p.part1),
+        part2: median_of(|p| p.part2),
+    };
 
-    if let Some(part2) = answer.part2 {
-        println!("part 2:");
-        let part2 = format!("{part2}");
-        for line in part2.lines() {
-            println!("\t{line}");
+    Ok(DayRun {
+        day,
+        parts,
+        result: result.expect("ran at least once"),
+        timing: Stats::from_samples(&samples).expect("ran at least once"),
+        phases,
+    })
+}
+
+/// Runs `f`, turning a panic into an error so that one broken day doesn't take down the rest.
+pub fn catch_panic<T>(f: impl FnOnce() -> anyhow::Result<T>) -> anyhow::Result<T> {
+    match std::panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(result) => result,
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .copied()
+                .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+                .unwrap_or("unknown panic payload");
+            Err(anyhow::anyhow!("panicked: {message}"))
         }
     }
+}
 
-    println!("Duration:");
-    println!("\t{} s", end.as_secs());
-    println!("\t{} ms", end.as_millis());
-    println!("\t{} us", end.as_micros());
-    println!("\t{} ns", end.as_nanos());
-    println!();
+/// A puzzle, identified by the year of its event and its day.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct DayId {
+    pub year: u32,
+    pub day: u32,
+}
 
-    Ok(())
+impl DayId {
+    pub const fn new(year: u32, day: u32) -> DayId {
+        DayId { year, day }
+    }
 }
 
+impl Display for DayId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.year, self.day)
+    }
+}
+
+/// Every solved day, ordered by year and then day.
+pub type Registry = BTreeMap<DayId, DayEntry>;
+
 #[derive(Debug, Eq, PartialEq)]
 pub enum Runnable {
-    Latest,                          // empty
-    All,                             // .
-    Range { first: u32, last: u32 }, // 12-15
+    Latest, // empty
+    All,    // . or ..
+    Range {
+        year: Option<u32>,
+        first: u32,
+        last: Option<u32>,
+        parts: Parts,
+    }, // 12-15, 20-, -5, 16.2, 12-15:p1, 2021/3, 2021/.
+    List {
+        include: Vec<Runnable>,
+        exclude: Vec<Runnable>,
+    }, // 1,3,7 or .,!16,!19
 }
 
 impl Runnable {
+    /// The days (and their parts) selected out of those in `registry`, in the order they were
+    /// asked for. Days without a year are taken from the latest year in the registry.
+    pub fn select(&self, registry: &Registry) -> Result<Vec<(DayId, Parts)>, ConversionError> {
+        let mut selected = Vec::new();
+        self.add_to(&mut selected, registry)?;
+        Ok(selected)
+    }
+
+    fn add_to(
+        &self,
+        selected: &mut Vec<(DayId, Parts)>,
+        registry: &Registry,
+    ) -> Result<(), ConversionError> {
+        match self {
+            Runnable::List { include, exclude } => {
+                let mut chosen = Vec::new();
+                if include.is_empty() {
+                    Runnable::All.add_to(&mut chosen, registry)?;
+                }
+                for runnable in include {
+                    runnable.add_to(&mut chosen, registry)?;
+                }
+
+                let mut skipped = Vec::new();
+                for runnable in exclude {
+                    runnable.add_to(&mut skipped, registry)?;
+                }
+
+                for (id, parts) in chosen {
+                    if skipped.iter().all(|&(skip, _)| skip != id) {
+                        merge(selected, id, parts);
+                    }
+                }
+            }
+            _ => {
+                for id in self.days(registry)? {
+                    merge(selected, id, self.parts());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn days(&self, registry: &Registry) -> Result<Vec<DayId>, ConversionError> {
+        let latest = registry.keys().next_back().map_or(0, |id| id.year);
+        let (year, first, last) = match *self {
+            Runnable::Latest => (latest, None, None),
+            Runnable::All => (latest, Some(1), None),
+            Runnable::Range {
+                year, first, last, ..
+            } => (year.unwrap_or(latest), Some(first), last),
+            Runnable::List { .. } => unreachable!("lists are expanded by add_to"),
+        };
+
+        let solved = registry
+            .keys()
+            .filter(|id| id.year == year)
+            .map(|id| id.day)
+            .collect::<Vec<_>>();
+        let Some(&count) = solved.last() else {
+            return Err(ConversionError::UnknownYear(year));
+        };
+        let days = first.unwrap_or(count)..=last.unwrap_or(count);
+        // a range can skip over unsolved days, but a single day has to exist
+        let missing = |day: &u32| {
+            *day == 0 || *day > count || (days.start() == days.end() && !solved.contains(day))
+        };
+        match days.clone().find(missing) {
+            Some(day) => Err(ConversionError::OutOfRange { year, day, count }),
+            None => Ok(solved
+                .into_iter()
+                .filter(|day| days.contains(day))
+                .map(|day| DayId::new(year, day))
+                .collect()),
+        }
+    }
+
+    fn parts(&self) -> Parts {
+        match *self {
+            Runnable::Range { parts, .. } => parts,
+            _ => Parts::Both,
+        }
+    }
+
     pub fn load_all<I: IntoIterator<Item = T>, T: AsRef<str>>(
         source: I,
     ) -> Result<Vec<Runnable>, ConversionError> {
@@ -211,19 +732,47 @@ impl Runnable {
         }
         Ok(runnables)
     }
+
+    fn check_order(&self) -> Result<(), ConversionError> {
+        match self {
+            &Runnable::Range {
+                first,
+                last: Some(last),
+                ..
+            } if first > last => Err(ConversionError::OutOfOrder { first, last }),
+            Runnable::List { include, exclude } => include
+                .iter()
+                .chain(exclude)
+                .try_for_each(Runnable::check_order),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Adds `id` to the selection, or widens the parts already selected for it.
+fn merge(selected: &mut Vec<(DayId, Parts)>, id: DayId, parts: Parts) {
+    match selected.iter_mut().find(|(seen, _)| *seen == id) {
+        Some((_, seen)) => *seen = seen.union(parts),
+        None => selected.push((id, parts)),
+    }
 }
 
 impl<'a> TryFrom<&'a str> for Runnable {
     type Error = ConversionError;
 
     fn try_from(value: &'a str) -> Result<Self, Self::Error> {
-        let res = parse_runnable(value).map(|r| r.1)?;
-
-        if let Runnable::Range { first, last } = res {
-            if first > last {
-                return Err(ConversionError::OutOfOrder);
+        let res = match parse_runnable(value) {
+            Ok((_, res)) => res,
+            Err(nom::Err::Error(err) | nom::Err::Failure(err)) => {
+                return Err(ConversionError::Unexpected {
+                    input: value.to_string(),
+                    column: value.len() - err.input.len() + 1,
+                })
             }
-        }
+            Err(err) => return Err(err.into()),
+        };
+
+        res.check_order()?;
 
         Ok(res)
     }
@@ -233,8 +782,17 @@ impl<'a> TryFrom<&'a str> for Runnable {
 pub enum ConversionError {
     #[error("Input was incomplete")]
     Incomplete,
-    #[error("Day range was not increasing")]
-    OutOfOrder,
+    #[error("Day range {first}-{last} was not increasing")]
+    OutOfOrder { first: u32, last: u32 },
+    #[error("Day {year}/{day} doesn't exist, expected a day from 1 to {count}")]
+    OutOfRange { year: u32, day: u32, count: u32 },
+    #[error("There are no solutions for {0}")]
+    UnknownYear(u32),
+    #[error(
+        "Couldn't parse day selection {input:?} at column {column}, expected comma separated days \
+         like 3, 1-5, 20-, -5, 16.2, 16:p1, 2021/3, 2021/., . or .., or exclusions like !16"
+    )]
+    Unexpected { input: String, column: usize },
     #[error("Parse error: {0}")]
     ParseError(String),
     #[error("Parse failure: {0}")]
@@ -254,29 +812,94 @@ impl<T> From<nom::Err<nom::error::Error<T>>> for ConversionError {
 }
 
 fn parse_runnable(input: &str) -> nom::IResult<&str, Runnable> {
+    // once there's a comma there has to be another item, so a bad one is reported where it is
+    // rather than at the comma
+    map(
+        all_consuming(separated_list1(tag(","), cut(parse_item))),
+        |items| {
+            let mut include = Vec::new();
+            let mut exclude = Vec::new();
+            for (excluded, runnable) in items {
+                if excluded {
+                    exclude.push(runnable);
+                } else {
+                    include.push(runnable);
+                }
+            }
+            if include.len() == 1 && exclude.is_empty() {
+                include.remove(0)
+            } else {
+                Runnable::List { include, exclude }
+            }
+        },
+    )(input)
+}
+
+/// A day selection, or an exclusion marked with a leading `!`, either of which may start with
+/// the year like `2021/`.
+fn parse_item(input: &str) -> nom::IResult<&str, (bool, Runnable)> {
+    let range = |year, (first, last), parts: Option<Parts>| Runnable::Range {
+        year,
+        first,
+        last,
+        parts: parts.unwrap_or_default(),
+    };
     alt((
-        map(parse_latest, |_| Runnable::All),
-        map(parse_range, |(first, last)| Runnable::Range {
-            first,
-            last: last.unwrap_or(first),
+        map(
+            preceded(tag("!"), pair(opt(parse_year), parse_days)),
+            move |(year, days)| (true, range(year, days, None)),
+        ),
+        map(parse_latest, |_| (false, Runnable::All)),
+        map(terminated(parse_year, parse_latest), move |year| {
+            (false, range(Some(year), (1, None), None))
         }),
+        map(
+            tuple((opt(parse_year), parse_days, opt(parse_parts))),
+            move |(year, days, parts)| (false, range(year, days, parts)),
+        ),
     ))(input)
 }
 
+fn parse_year(input: &str) -> nom::IResult<&str, u32> {
+    terminated(character::u32, tag("/"))(input)
+}
+
 fn parse_latest(input: &str) -> nom::IResult<&str, &str> {
-    all_consuming(tag("."))(input)
+    alt((tag(".."), tag(".")))(input)
 }
 
-fn parse_range(input: &str) -> nom::IResult<&str, (u32, Option<u32>)> {
-    all_consuming(pair(
-        character::u32,
-        opt(preceded(tag("-"), character::u32)),
+/// `N`, `N-M`, `N-` (to the last day) or `-M` (from the first day).
+fn parse_days(input: &str) -> nom::IResult<&str, (u32, Option<u32>)> {
+    alt((
+        map(preceded(tag("-"), character::u32), |last| (1, Some(last))),
+        map(
+            pair(character::u32, opt(preceded(tag("-"), opt(character::u32)))),
+            |(first, last)| (first, last.unwrap_or(Some(first))),
+        ),
     ))(input)
 }
 
+fn parse_parts(input: &str) -> nom::IResult<&str, Parts> {
+    preceded(
+        alt((tag("."), tag(":p"))),
+        alt((
+            map(tag("1"), |_| Parts::Part1),
+            map(tag("2"), |_| Parts::Part2),
+        )),
+    )(input)
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{parse_runnable, Runnable};
+    use crate::days::y2022::day20::{self, Day20};
+    use crate::input::InputError;
+    use crate::params::DayParams;
+    use crate::timing::Repeat;
+    use crate::{
+        catch_panic, days, parse_runnable, run_day, run_phases, Answers, ConversionError, DayEntry,
+        DayId, Parts, Registry, Runnable,
+    };
+    use num::bigint::{BigInt, BigUint};
 
     #[test]
     fn no_args_defaults_to_latest() {
@@ -294,4 +917,303 @@ mod tests {
     fn parser_handles_latest() {
         assert_eq!(parse_runnable("."), Ok(("", Runnable::All)));
     }
+
+    #[test]
+    fn phased_days_time_each_part() {
+        let input = include_str!("../input/2022/test/20.txt");
+        let params = DayParams::defaults(day20::PARAMS, true);
+        let (result, phases) = run_phases::<Day20>(input, &params, Parts::Both).unwrap();
+        assert_eq!(result.part1, Some(3.into()));
+        assert!(phases.part1.is_some());
+        assert!(phases.part2.is_some());
+
+        let (result, phases) = run_phases::<Day20>(input, &params, Parts::Part2).unwrap();
+        assert_eq!(result.part1, None);
+        assert_eq!(phases.part1, None);
+        assert_eq!(result.part2, Some(1_623_178_306.into()));
+    }
+
+    #[test]
+    fn answers_compare_by_kind_and_value() {
+        assert_eq!(Answers::U32(5), Answers::I64(5));
+        assert_eq!(Answers::U128(u128::MAX), BigInt::from(u128::MAX).into());
+        assert_ne!(Answers::U32(5), Answers::from("5"));
+        assert_ne!(Answers::I8(-1), Answers::U8(255));
+        assert!(!Answers::U32(5).matches(&Answers::U64(5), true));
+        assert!(Answers::U32(5).matches(&Answers::U32(5), true));
+
+        let list = Answers::list([1_u32, 2, 3]);
+        assert_eq!(list, Answers::list([1_i64, 2, 3]));
+        assert!(!list.matches(&Answers::list([1_i64, 2, 3]), true));
+        assert_ne!(list, Answers::list([1_u32, 2]));
+        assert_ne!(list, Answers::from("1,2,3"));
+        assert_eq!(Answers::grid(["#.", ".#"]), Answers::grid(["#.", ".#"]));
+        assert_ne!(Answers::grid(["#.", ".#"]), Answers::from("#.\n.#\n"));
+    }
+
+    #[test]
+    fn answers_have_a_canonical_form() {
+        assert_eq!(Answers::I128(-3).to_string(), "-3");
+        assert_eq!(
+            Answers::from(BigUint::from(10_u8).pow(30)).to_string(),
+            "1000000000000000000000000000000"
+        );
+        assert_eq!(Answers::list([3_u8, 1, 2]).to_string(), "3,1,2");
+        assert_eq!(Answers::list(["a", "b"]).to_string(), "a,b");
+        assert_eq!(Answers::grid(["#.", ".#"]).to_string(), "#.\n.#\n");
+        assert_eq!(Answers::grid(["#."]).variant_name(), "Grid");
+    }
+
+    #[test]
+    fn panics_become_errors() {
+        let result = catch_panic::<()>(|| unreachable!("bad input {}", 3));
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "panicked: internal error: entered unreachable code: bad input 3"
+        );
+        assert_eq!(catch_panic(|| Ok(7)).unwrap(), 7);
+    }
+
+    #[test]
+    fn input_errors_name_their_day() {
+        let id = DayId::new(2022, 4);
+        let entry = days::get_days()[&id];
+        let params = DayParams::default();
+        let run = run_day(
+            id,
+            &entry,
+            "2-4,6-8\n2-3,x-5\n",
+            &params,
+            Parts::Both,
+            Repeat::default(),
+            false,
+        );
+        let err = run.unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<InputError>().unwrap().to_string(),
+            "day 2022/4, line 2, column 5: expected a pair of ranges like 2-4,6-8, found 'x'"
+        );
+    }
+
+    #[test]
+    fn inputs_are_normalised_unless_strict() {
+        let id = DayId::new(2022, 1);
+        let entry = days::get_days()[&id];
+        let params = DayParams::default();
+        let input = "1000\r\n2000\r\n\r\n3000";
+        let run = |strict| {
+            run_day(
+                id,
+                &entry,
+                input,
+                &params,
+                Parts::Both,
+                Repeat::default(),
+                strict,
+            )
+        };
+
+        let result = run(false).unwrap().result;
+        assert_eq!(result.part1, Some(3_000.into()));
+        assert_eq!(result.part2, Some(6_000.into()));
+        assert_eq!(
+            run(true).unwrap_err().to_string(),
+            "the input isn't canonical, normalising it would have changed 3 \\r\\n line endings \
+             to \\n, added a newline at the end"
+        );
+    }
+
+    #[test]
+    fn parser_handles_parts() {
+        let range = |first, last, parts| Runnable::Range {
+            year: None,
+            first,
+            last: Some(last),
+            parts,
+        };
+        assert_eq!(parse_runnable("16"), Ok(("", range(16, 16, Parts::Both))));
+        assert_eq!(
+            parse_runnable("16.2"),
+            Ok(("", range(16, 16, Parts::Part2)))
+        );
+        assert_eq!(
+            parse_runnable("16:p2"),
+            Ok(("", range(16, 16, Parts::Part2)))
+        );
+        assert_eq!(
+            parse_runnable("12-15.1"),
+            Ok(("", range(12, 15, Parts::Part1)))
+        );
+        assert!(parse_runnable("16.3").is_err());
+    }
+
+    #[test]
+    fn parser_handles_years() {
+        let range = |year, first, last| Runnable::Range {
+            year: Some(year),
+            first,
+            last,
+            parts: Parts::Both,
+        };
+        assert_eq!(
+            parse_runnable("2021/16"),
+            Ok(("", range(2021, 16, Some(16))))
+        );
+        assert_eq!(parse_runnable("2021/."), Ok(("", range(2021, 1, None))));
+        assert_eq!(parse_runnable("2021/3-"), Ok(("", range(2021, 3, None))));
+        assert!(parse_runnable("2021/").is_err());
+    }
+
+    /// 25 days of 2022, and days 1 to 3 and 5 of 2021.
+    fn registry() -> Registry {
+        let entry = DayEntry {
+            title: "",
+            f: |_, _, _| unreachable!(),
+            params: &[],
+            real: None,
+            test: None,
+            example: [None, None],
+            slow: false,
+        };
+        (1..=25)
+            .map(|day| DayId::new(2022, day))
+            .chain([1, 2, 3, 5].map(|day| DayId::new(2021, day)))
+            .map(|id| (id, entry))
+            .collect()
+    }
+
+    #[test]
+    fn lists_open_ranges_and_exclusions() {
+        let registry = registry();
+        let select = |arg: &str| Runnable::try_from(arg).and_then(|r| r.select(&registry));
+        let both = |days: &[u32]| {
+            days.iter()
+                .map(|&d| (DayId::new(2022, d), Parts::Both))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(select("1,3,7"), Ok(both(&[1, 3, 7])));
+        assert_eq!(select("20-"), Ok(both(&[20, 21, 22, 23, 24, 25])));
+        assert_eq!(select("-3"), Ok(both(&[1, 2, 3])));
+        assert_eq!(select(".."), select("."));
+        assert_eq!(select("20-,!22-24"), Ok(both(&[20, 21, 25])));
+        assert_eq!(select("!2-25"), Ok(both(&[1])));
+        assert_eq!(select("16.1,3,16:p2,3.1"), Ok(both(&[16, 3])));
+
+        let all = select(".,!16,!19").unwrap();
+        assert_eq!(all.len(), 23);
+        assert!(all.iter().all(|&(id, _)| id.day != 16 && id.day != 19));
+        assert_eq!(Runnable::Latest.select(&registry), Ok(both(&[25])));
+    }
+
+    #[test]
+    fn years_are_selected() {
+        let registry = registry();
+        let select = |arg: &str| Runnable::try_from(arg).and_then(|r| r.select(&registry));
+        let day = |year, day| (DayId::new(year, day), Parts::Both);
+
+        assert_eq!(
+            select("2021/.,25"),
+            Ok(vec![
+                day(2021, 1),
+                day(2021, 2),
+                day(2021, 3),
+                day(2021, 5),
+                day(2022, 25)
+            ])
+        );
+        assert_eq!(
+            select("2021/2-,2022/1.2"),
+            Ok(vec![
+                day(2021, 2),
+                day(2021, 3),
+                day(2021, 5),
+                (DayId::new(2022, 1), Parts::Part2)
+            ])
+        );
+        assert_eq!(
+            select("2021/-4,!2021/2"),
+            Ok(vec![day(2021, 1), day(2021, 3)])
+        );
+        // exclusions without a year are from the latest year
+        assert_eq!(select("2021/1,!1"), Ok(vec![day(2021, 1)]));
+        assert_eq!(DayId::new(2021, 3).to_string(), "2021/3");
+    }
+
+    #[test]
+    fn bad_selections_are_rejected() {
+        let registry = registry();
+        assert_eq!(
+            Runnable::try_from("30").unwrap().select(&registry),
+            Err(ConversionError::OutOfRange {
+                year: 2022,
+                day: 30,
+                count: 25
+            })
+        );
+        assert_eq!(
+            Runnable::try_from("0-3").unwrap().select(&registry),
+            Err(ConversionError::OutOfRange {
+                year: 2022,
+                day: 0,
+                count: 25
+            })
+        );
+        assert_eq!(
+            Runnable::try_from("2021/4").unwrap().select(&registry),
+            Err(ConversionError::OutOfRange {
+                year: 2021,
+                day: 4,
+                count: 5
+            })
+        );
+        assert_eq!(
+            Runnable::try_from("2021/4-6").unwrap().select(&registry),
+            Err(ConversionError::OutOfRange {
+                year: 2021,
+                day: 6,
+                count: 5
+            })
+        );
+        assert_eq!(
+            Runnable::try_from("2015/1").unwrap().select(&registry),
+            Err(ConversionError::UnknownYear(2015))
+        );
+        assert_eq!(
+            Runnable::try_from("1,15-12"),
+            Err(ConversionError::OutOfOrder {
+                first: 15,
+                last: 12
+            })
+        );
+        assert_eq!(
+            Runnable::try_from("1,x"),
+            Err(ConversionError::Unexpected {
+                input: "1,x".to_string(),
+                column: 3
+            })
+        );
+        assert_eq!(
+            Runnable::try_from("1,"),
+            Err(ConversionError::Unexpected {
+                input: "1,".to_string(),
+                column: 3
+            })
+        );
+        assert_eq!(
+            Runnable::try_from("x"),
+            Err(ConversionError::Unexpected {
+                input: "x".to_string(),
+                column: 1
+            })
+        );
+        assert_eq!(
+            Runnable::try_from("1,2x"),
+            Err(ConversionError::Unexpected {
+                input: "1,2x".to_string(),
+                column: 4
+            })
+        );
+        assert!(Runnable::try_from("!16.2").is_err());
+    }
 }
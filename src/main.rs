@@ -1,157 +1,176 @@
-use advent_of_code_2022::{days, DayEntry};
-use advent_of_code_2022::{run_day, Runnable};
+use std::collections::{BTreeMap, BTreeSet};
+use std::num::NonZeroUsize;
+use std::path::Path;
 
-use anyhow::Context;
+use advent_of_code_2022::answers::{AnswerSheet, Verdict};
+use advent_of_code_2022::days::get_days;
+use advent_of_code_2022::options::Options;
+use advent_of_code_2022::output::Reporter;
+use advent_of_code_2022::params::{DayParams, ParamOverrides};
+use advent_of_code_2022::pool::run_in_order;
+use advent_of_code_2022::scaffold;
+use advent_of_code_2022::timeout::run_with_timeout;
+use advent_of_code_2022::{catch_panic, run_day, DayEntry, DayId, Parts, Registry};
 
-fn main() -> anyhow::Result<()> {
-    let is_test = std::env::var_os("TEST").is_some();
+use anyhow::{anyhow, bail, Context};
+
+type Job = (DayId, Parts, DayEntry, DayParams);
 
+fn main() -> anyhow::Result<()> {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
     let days = get_days();
 
-    let runnables =
-        Runnable::load_all(std::env::args().skip(1)).context("failed to parse runnables")?;
+    match args.split_first().map(|(cmd, rest)| (cmd.as_str(), rest)) {
+        Some(("new", args)) => return new_day(args, &days),
+        Some(("list", [])) => return list_days(&days),
+        _ => {}
+    }
+
+    let options = Options::load_all(args).context("failed to parse args")?;
+    let is_test = options.is_test || std::env::var_os("TEST").is_some();
+
+    let mut params = match &options.params_file {
+        Some(path) => ParamOverrides::load(path)?,
+        None => ParamOverrides::default(),
+    };
+    params.extend(options.params);
+    params.set_latest_year(days.keys().next_back().map_or(0, |id| id.year));
+    params.validate(days.iter().map(|(&id, entry)| (id, entry.params)))?;
+
+    let mut reporter = Reporter::new(options.format, std::io::stdout().lock());
+    let mut runs = Vec::new();
+
+    let mut failed = false;
+    let mut failures = Vec::new();
+
+    let mut jobs = Vec::new();
+    for runnable in &options.runnables {
+        let selected = runnable.select(&days)?;
+
+        if options.input.is_single() && selected.len() > 1 {
+            bail!("a single input file can only be used to run a single day");
+        }
+
+        for (id, parts) in selected {
+            let entry = days[&id];
+            if options.skip_slow && entry.slow {
+                continue;
+            }
+            let params = params.resolve(id, entry.params, is_test)?;
+            jobs.push((id, parts, entry, params));
+        }
+    }
+    let attempted = jobs.len();
+
+    // one answer sheet per year
+    let sheet_path = |year| AnswerSheet::path(Path::new("answers"), year, is_test);
+    let mut sheets = BTreeMap::new();
+    if options.check || options.record {
+        let years = jobs.iter().map(|(id, ..)| id.year).collect::<BTreeSet<_>>();
+        for year in years {
+            sheets.insert(year, AnswerSheet::load(&sheet_path(year))?);
+        }
+    }
+
+    // days are timed on the worker running them, so with no more jobs than cores each timing
+    // matches running the day on its own
+    let run_job = |(day, parts, entry, params): Job| {
+        let source = options.input.clone();
+        let repeat = options.repeat;
+        let strict_input = options.strict_input;
+        let attempt = move || {
+            let input = source.load(day, &entry, is_test)?;
+            run_day(day, &entry, &input, &params, parts, repeat, strict_input)
+        };
+
+        // `None` if the day ran out of time
+        let outcome = match options.timeout {
+            Some(limit) => run_with_timeout(limit, attempt),
+            None if options.keep_going => Some(catch_panic(attempt)),
+            None => Some(attempt()),
+        };
+        (day, parts, outcome)
+    };
 
-    for runnable in runnables {
-        let days_to_run = match runnable {
-            Runnable::Latest => {
-                let day = days.len() as u32;
-                day..=day
+    let threads = options.jobs.map_or(1, NonZeroUsize::get);
+    run_in_order(threads, jobs, run_job, |(day, parts, outcome)| {
+        let run = match outcome {
+            Some(Ok(run)) => run,
+            None => {
+                let limit = options.timeout.unwrap_or_default();
+                reporter.timeout(day, limit)?;
+                failures.push((day, anyhow!("timed out after {limit:?}")));
+                return Ok(());
             }
-            Runnable::All => {
-                let last = days.len() as u32;
-                1..=last
+            Some(Err(err)) if options.keep_going => {
+                reporter.failure(day, &err)?;
+                failures.push((day, err));
+                return Ok(());
             }
-            Runnable::Range { first, last } => first..=last,
+            Some(Err(err)) => return Err(err.context(format!("day {day} failed"))),
         };
-        days_to_run
-            .into_iter()
-            .try_for_each(|day| run_day(day, &days[(day - 1) as usize], is_test))?;
+        reporter.report(&run)?;
+
+        let sheet = sheets.entry(day.year).or_default();
+        if options.check {
+            let verdicts = sheet.check(day.day, parts, &run.result);
+            failed |= verdicts
+                .iter()
+                .any(|(_, verdict)| matches!(verdict, Verdict::Fail { .. }));
+            reporter.check(day, &verdicts)?;
+        }
+        if options.record {
+            sheet.record(day.day, &run.result);
+        }
+
+        runs.push(run);
+        Ok(())
+    })?;
+
+    if options.repeat.runs > 1 {
+        reporter.summary(&runs)?;
+    }
+
+    if options.record {
+        for (&year, sheet) in &sheets {
+            sheet.save(&sheet_path(year))?;
+        }
+    }
+
+    if !failures.is_empty() {
+        reporter.failures(&failures)?;
+        bail!("{} of {attempted} days failed", failures.len());
     }
 
+    if failed {
+        bail!("some answers didn't match the answer sheets");
+    }
+
+    Ok(())
+}
+
+/// `new <day> [title]`: creates and registers a day from the template, in the crate it's run
+/// from. The day can be `<year>/<day>`, or just the day of the latest year.
+fn new_day(args: &[String], days: &Registry) -> anyhow::Result<()> {
+    let (day, title) = match args {
+        [day] => (day, ""),
+        [day, title] => (day, title.as_str()),
+        _ => bail!("usage: new <day> [title], where the day is like 5 or 2023/5"),
+    };
+    let latest = days.keys().next_back().map_or(0, |id| id.year);
+    let id = scaffold::parse_day(day, latest)?;
+    let root = std::env::current_dir().context("failed to find the current directory")?;
+    for path in scaffold::new_day(&root, id, title)? {
+        println!("wrote {}", path.display());
+    }
     Ok(())
 }
 
-fn get_days() -> Vec<DayEntry> {
-    vec![
-        DayEntry {
-            f: days::day01::run,
-            real: include_str!("../input/real/01.txt"),
-            test: include_str!("../input/test/01.txt"),
-        },
-        DayEntry {
-            f: days::day02::run,
-            real: include_str!("../input/real/02.txt"),
-            test: include_str!("../input/test/02.txt"),
-        },
-        DayEntry {
-            f: days::day03::run,
-            real: include_str!("../input/real/03.txt"),
-            test: include_str!("../input/test/03.txt"),
-        },
-        DayEntry {
-            f: days::day04::run,
-            real: include_str!("../input/real/04.txt"),
-            test: include_str!("../input/test/04.txt"),
-        },
-        DayEntry {
-            f: days::day05::run,
-            real: include_str!("../input/real/05.txt"),
-            test: include_str!("../input/test/05.txt"),
-        },
-        DayEntry {
-            f: days::day06::run,
-            real: include_str!("../input/real/06.txt"),
-            test: include_str!("../input/test/06.txt"),
-        },
-        DayEntry {
-            f: days::day07::run,
-            real: include_str!("../input/real/07.txt"),
-            test: include_str!("../input/test/07.txt"),
-        },
-        DayEntry {
-            f: days::day08::run,
-            real: include_str!("../input/real/08.txt"),
-            test: include_str!("../input/test/08.txt"),
-        },
-        DayEntry {
-            f: days::day09::run,
-            real: include_str!("../input/real/09.txt"),
-            test: include_str!("../input/test/09.txt"),
-        },
-        DayEntry {
-            f: days::day10::run,
-            real: include_str!("../input/real/10.txt"),
-            test: include_str!("../input/test/10.txt"),
-        },
-        DayEntry {
-            f: days::day11::run,
-            real: include_str!("../input/real/11.txt"),
-            test: include_str!("../input/test/11.txt"),
-        },
-        DayEntry {
-            f: days::day12::run,
-            real: include_str!("../input/real/12.txt"),
-            test: include_str!("../input/test/12.txt"),
-        },
-        DayEntry {
-            f: days::day13::run,
-            real: include_str!("../input/real/13.txt"),
-            test: include_str!("../input/test/13.txt"),
-        },
-        DayEntry {
-            f: days::day14::run,
-            real: include_str!("../input/real/14.txt"),
-            test: include_str!("../input/test/14.txt"),
-        },
-        DayEntry {
-            f: days::day15::run,
-            real: include_str!("../input/real/15.txt"),
-            test: include_str!("../input/test/15.txt"),
-        },
-        DayEntry {
-            f: days::day16::run,
-            real: include_str!("../input/real/16.txt"),
-            test: include_str!("../input/test/16.txt"),
-        },
-        DayEntry {
-            f: days::day17::run,
-            real: include_str!("../input/real/17.txt"),
-            test: include_str!("../input/test/17.txt"),
-        },
-        DayEntry {
-            f: days::day18::run,
-            real: include_str!("../input/real/18.txt"),
-            test: include_str!("../input/test/18.txt"),
-        },
-        DayEntry {
-            f: days::day19::run,
-            real: include_str!("../input/real/19.txt"),
-            test: include_str!("../input/test/19.txt"),
-        },
-        DayEntry {
-            f: days::day20::run,
-            real: include_str!("../input/real/20.txt"),
-            test: include_str!("../input/test/20.txt"),
-        },
-        DayEntry {
-            f: days::day21::run,
-            real: include_str!("../input/real/21.txt"),
-            test: include_str!("../input/test/21.txt"),
-        },
-        DayEntry {
-            f: days::day22::run,
-            real: include_str!("../input/real/22.txt"),
-            test: include_str!("../input/test/22.txt"),
-        },
-        DayEntry {
-            f: days::day23::run,
-            real: include_str!("../input/real/23.txt"),
-            test: include_str!("../input/test/23.txt"),
-        },
-        DayEntry {
-            f: days::day24::run,
-            real: include_str!("../input/real/24.txt"),
-            test: include_str!("../input/test/24.txt"),
-        },
-    ]
+/// `list`: prints every registered day.
+fn list_days(days: &Registry) -> anyhow::Result<()> {
+    for (id, entry) in days {
+        let slow = if entry.slow { " (slow)" } else { "" };
+        println!("{id}\t{}{slow}", entry.title);
+    }
+    Ok(())
 }
@@ -0,0 +1,288 @@
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+use thiserror::Error;
+
+use crate::input::InputSource;
+use crate::output::OutputFormat;
+use crate::params::{ParamError, ParamOverrides};
+use crate::timing::Repeat;
+use crate::{ConversionError, Runnable};
+
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct Options {
+    pub runnables: Vec<Runnable>,
+    pub input: InputSource,
+    pub is_test: bool,
+    pub format: OutputFormat,
+    pub repeat: Repeat,
+    /// compare each answer against the answer sheet
+    pub check: bool,
+    /// write each answer into the answer sheet
+    pub record: bool,
+    /// carry on with the other days when one fails or panics
+    pub keep_going: bool,
+    /// how long each day gets before it's abandoned
+    pub timeout: Option<Duration>,
+    /// parameter values from `--param`, which win over the ones in `params_file`
+    pub params: ParamOverrides,
+    pub params_file: Option<PathBuf>,
+    /// how many days to run at once, one by default
+    pub jobs: Option<NonZeroUsize>,
+    /// leave out the days marked as slow
+    pub skip_slow: bool,
+    /// fail on inputs that need normalising rather than tidying them up
+    pub strict_input: bool,
+}
+
+impl Options {
+    pub fn load_all<I: IntoIterator<Item = T>, T: AsRef<str>>(
+        source: I,
+    ) -> Result<Options, OptionsError> {
+        let mut options = Options::default();
+        let mut runnables = Vec::new();
+
+        let mut args = source.into_iter();
+        while let Some(arg) = args.next() {
+            let arg = arg.as_ref();
+            match arg {
+                "--input" => {
+                    let path = next_value(&mut args, arg)?;
+                    options.input = if path == "-" {
+                        InputSource::Stdin
+                    } else {
+                        InputSource::File(PathBuf::from(path))
+                    };
+                }
+                "--inputs" => {
+                    options.input = InputSource::Dir(PathBuf::from(next_value(&mut args, arg)?));
+                }
+                "--embedded" => options.input = InputSource::Embedded,
+                "--test" => options.is_test = true,
+                "--format" => {
+                    let name = next_value(&mut args, arg)?;
+                    options.format =
+                        OutputFormat::parse(&name).ok_or_else(|| OptionsError::InvalidValue {
+                            flag: arg.to_string(),
+                            value: name,
+                        })?;
+                }
+                "--repeat" => options.repeat.runs = parse_value(&mut args, arg)?,
+                "--warmup" => options.repeat.warmup = parse_value(&mut args, arg)?,
+                "--check" => options.check = true,
+                "--record" => options.record = true,
+                "--keep-going" => options.keep_going = true,
+                "--timeout" => {
+                    let secs: f64 = parse_value(&mut args, arg)?;
+                    let timeout = Duration::try_from_secs_f64(secs).map_err(|_| {
+                        OptionsError::InvalidValue {
+                            flag: arg.to_string(),
+                            value: secs.to_string(),
+                        }
+                    })?;
+                    options.timeout = Some(timeout);
+                }
+                "--param" => options.params.add(&next_value(&mut args, arg)?)?,
+                "--jobs" => options.jobs = Some(parse_value(&mut args, arg)?),
+                "--skip-slow" => options.skip_slow = true,
+                "--strict-input" => options.strict_input = true,
+                "--params" => {
+                    options.params_file = Some(PathBuf::from(next_value(&mut args, arg)?));
+                }
+                flag if flag.starts_with("--") => {
+                    return Err(OptionsError::UnknownFlag(flag.to_string()))
+                }
+                runnable => runnables.push(runnable.to_string()),
+            }
+        }
+
+        options.runnables = Runnable::load_all(runnables)?;
+
+        Ok(options)
+    }
+}
+
+fn next_value<I: Iterator<Item = T>, T: AsRef<str>>(
+    args: &mut I,
+    flag: &str,
+) -> Result<String, OptionsError> {
+    args.next()
+        .map(|v| v.as_ref().to_string())
+        .ok_or_else(|| OptionsError::MissingValue(flag.to_string()))
+}
+
+fn parse_value<I: Iterator<Item = T>, T: AsRef<str>, V: FromStr>(
+    args: &mut I,
+    flag: &str,
+) -> Result<V, OptionsError> {
+    let value = next_value(args, flag)?;
+    value.parse().map_err(|_| OptionsError::InvalidValue {
+        flag: flag.to_string(),
+        value,
+    })
+}
+
+#[derive(Debug, Error, Eq, PartialEq)]
+pub enum OptionsError {
+    #[error("Missing value for {0}")]
+    MissingValue(String),
+    #[error("Invalid value {value:?} for {flag}")]
+    InvalidValue { flag: String, value: String },
+    #[error("Unknown flag {0}")]
+    UnknownFlag(String),
+    #[error(transparent)]
+    Runnable(#[from] ConversionError),
+    #[error(transparent)]
+    Param(#[from] ParamError),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Options, OptionsError};
+    use crate::input::InputSource;
+    use crate::output::OutputFormat;
+    use crate::params::{ParamError, ParamOverrides};
+    use crate::timing::Repeat;
+    use crate::{Parts, Runnable};
+    use std::num::NonZeroUsize;
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    #[test]
+    fn defaults_to_input_dir() {
+        let options = Options::load_all::<[&str; 0], _>([]);
+        assert_eq!(
+            options,
+            Ok(Options {
+                runnables: vec![Runnable::Latest],
+                input: InputSource::Dir(PathBuf::from("input")),
+                is_test: false,
+                format: OutputFormat::Text,
+                repeat: Repeat { warmup: 0, runs: 1 },
+                check: false,
+                record: false,
+                keep_going: false,
+                timeout: None,
+                params: ParamOverrides::default(),
+                params_file: None,
+                jobs: None,
+                skip_slow: false,
+                strict_input: false,
+            })
+        );
+    }
+
+    #[test]
+    fn input_flags_are_parsed() {
+        let options = Options::load_all(["--input", "mine.txt", "3"]).unwrap();
+        assert_eq!(options.input, InputSource::File(PathBuf::from("mine.txt")));
+        assert_eq!(
+            options.runnables,
+            vec![Runnable::Range {
+                year: None,
+                first: 3,
+                last: Some(3),
+                parts: Parts::Both
+            }]
+        );
+
+        let options = Options::load_all(["--input", "-"]).unwrap();
+        assert_eq!(options.input, InputSource::Stdin);
+
+        let options = Options::load_all(["--inputs", "elsewhere", "--test"]).unwrap();
+        assert_eq!(options.input, InputSource::Dir(PathBuf::from("elsewhere")));
+        assert!(options.is_test);
+
+        let options = Options::load_all(["--check", "--record", "--keep-going", "."]).unwrap();
+        assert!(options.check && options.record && options.keep_going);
+
+        let options = Options::load_all(["--strict-input"]).unwrap();
+        assert!(options.strict_input);
+
+        let options = Options::load_all(["--timeout", "2.5"]).unwrap();
+        assert_eq!(options.timeout, Some(Duration::from_millis(2_500)));
+        assert_eq!(
+            Options::load_all(["--timeout", "-1"]),
+            Err(OptionsError::InvalidValue {
+                flag: "--timeout".to_string(),
+                value: "-1".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn format_flag_is_parsed() {
+        let options = Options::load_all(["--format", "csv", "."]).unwrap();
+        assert_eq!(options.format, OutputFormat::Csv);
+        assert_eq!(
+            Options::load_all(["--format", "xml"]),
+            Err(OptionsError::InvalidValue {
+                flag: "--format".to_string(),
+                value: "xml".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn repeat_flags_are_parsed() {
+        let options = Options::load_all(["--jobs", "4", "--skip-slow"]).unwrap();
+        assert_eq!(options.jobs, NonZeroUsize::new(4));
+        assert!(options.skip_slow);
+        assert_eq!(
+            Options::load_all(["--jobs", "0"]),
+            Err(OptionsError::InvalidValue {
+                flag: "--jobs".to_string(),
+                value: "0".to_string(),
+            })
+        );
+
+        let options = Options::load_all(["--repeat", "20", "--warmup", "3"]).unwrap();
+        assert_eq!(
+            options.repeat,
+            Repeat {
+                warmup: 3,
+                runs: 20
+            }
+        );
+        assert_eq!(
+            Options::load_all(["--repeat", "lots"]),
+            Err(OptionsError::InvalidValue {
+                flag: "--repeat".to_string(),
+                value: "lots".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn param_flags_are_parsed() {
+        let options =
+            Options::load_all(["--param", "row=10", "--param", "16.part2_minutes=20", "15"])
+                .unwrap();
+        let mut params = ParamOverrides::default();
+        params.add("row=10").unwrap();
+        params.add("16.part2_minutes=20").unwrap();
+        assert_eq!(options.params, params);
+
+        let options = Options::load_all(["--params", "params.toml"]).unwrap();
+        assert_eq!(options.params_file, Some(PathBuf::from("params.toml")));
+
+        assert_eq!(
+            Options::load_all(["--param", "row"]),
+            Err(OptionsError::Param(ParamError::Invalid("row".to_string())))
+        );
+    }
+
+    #[test]
+    fn bad_flags_are_rejected() {
+        assert_eq!(
+            Options::load_all(["--input"]),
+            Err(OptionsError::MissingValue("--input".to_string()))
+        );
+        assert_eq!(
+            Options::load_all(["--nope"]),
+            Err(OptionsError::UnknownFlag("--nope".to_string()))
+        );
+    }
+}
@@ -0,0 +1,501 @@
+use std::io::Write;
+
+use std::time::Duration;
+
+use crate::answers::Verdict;
+use crate::timing::Stats;
+use crate::{Answers, DayId, DayRun};
+
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    /// one JSON object per line
+    Json,
+    /// a header row followed by one row per day
+    Csv,
+}
+
+impl OutputFormat {
+    pub fn parse(name: &str) -> Option<OutputFormat> {
+        match name {
+            "text" => Some(OutputFormat::Text),
+            "json" => Some(OutputFormat::Json),
+            "csv" => Some(OutputFormat::Csv),
+            _ => None,
+        }
+    }
+}
+
+const CSV_HEADER: &str =
+    "year,day,part1,part1_type,part2,part2_type,elapsed_ns,runs,min_ns,median_ns,mean_ns,p95_ns,stddev_ns,parse_ns,part1_ns,part2_ns";
+
+/// Writes one record per day in the chosen format.
+pub struct Reporter<W> {
+    format: OutputFormat,
+    out: W,
+    wrote_header: bool,
+}
+
+impl<W: Write> Reporter<W> {
+    pub fn new(format: OutputFormat, out: W) -> Self {
+        Reporter {
+            format,
+            out,
+            wrote_header: false,
+        }
+    }
+
+    pub fn report(&mut self, run: &DayRun) -> std::io::Result<()> {
+        match self.format {
+            OutputFormat::Text => self.write_text(run),
+            OutputFormat::Json => self.write_json(run),
+            OutputFormat::Csv => self.write_csv(run),
+        }
+    }
+
+    fn write_text(&mut self, run: &DayRun) -> std::io::Result<()> {
+        let out = &mut self.out;
+        writeln!(out, "day {}:", run.day)?;
+
+        let parts = [
+            ("part 1", run.parts.has_part1(), &run.result.part1),
+            ("part 2", run.parts.has_part2(), &run.result.part2),
+        ];
+        for (name, selected, part) in parts {
+            if !selected {
+                writeln!(out, "{name}:")?;
+                writeln!(out, "\tskipped")?;
+            } else if let Some(part) = part {
+                writeln!(out, "{name}:")?;
+                let part = format!("{part}");
+                for line in part.lines() {
+                    writeln!(out, "\t{line}")?;
+                }
+            }
+        }
+
+        let timing = run.timing;
+        if timing.runs == 1 {
+            let elapsed = timing.median;
+            writeln!(out, "Duration:")?;
+            writeln!(out, "\t{} s", elapsed.as_secs())?;
+            writeln!(out, "\t{} ms", elapsed.as_millis())?;
+            writeln!(out, "\t{} us", elapsed.as_micros())?;
+            writeln!(out, "\t{} ns", elapsed.as_nanos())?;
+        } else {
+            writeln!(out, "Duration over {} runs:", timing.runs)?;
+            writeln!(out, "\tmin:    {:?}", timing.min)?;
+            writeln!(out, "\tmedian: {:?}", timing.median)?;
+            writeln!(out, "\tmean:   {:?}", timing.mean)?;
+            writeln!(out, "\tp95:    {:?}", timing.p95)?;
+            writeln!(out, "\tstddev: {:?}", timing.stddev)?;
+        }
+
+        let phases = run.phases;
+        if phases.part1.is_some() || phases.part2.is_some() {
+            writeln!(out, "Phases:")?;
+            writeln!(out, "\tparse:  {:?}", phases.parse)?;
+            if let Some(part1) = phases.part1 {
+                writeln!(out, "\tpart 1: {part1:?}")?;
+            }
+            if let Some(part2) = phases.part2 {
+                writeln!(out, "\tpart 2: {part2:?}")?;
+            }
+        }
+        writeln!(out)
+    }
+
+    /// Reports a day that returned an error or panicked instead of producing a result.
+    pub fn failure(&mut self, day: DayId, err: &anyhow::Error) -> std::io::Result<()> {
+        let message = format!("{err:#}");
+        match self.format {
+            OutputFormat::Text => {
+                writeln!(self.out, "day {day}:")?;
+                writeln!(self.out, "FAILED:")?;
+                for line in message.lines() {
+                    writeln!(self.out, "\t{line}")?;
+                }
+                writeln!(self.out)
+            }
+            OutputFormat::Json => writeln!(
+                self.out,
+                r#"{{"year":{},"day":{},"error":{}}}"#,
+                day.year,
+                day.day,
+                json_string(&message)
+            ),
+            // a failure has none of the columns, so keep it out of the table
+            OutputFormat::Csv => {
+                eprintln!("day {day} failed: {message}");
+                Ok(())
+            }
+        }
+    }
+
+    /// Reports a day that was abandoned after running for `limit`.
+    pub fn timeout(&mut self, day: DayId, limit: Duration) -> std::io::Result<()> {
+        match self.format {
+            OutputFormat::Text => {
+                writeln!(self.out, "day {day}:")?;
+                writeln!(self.out, "TIMEOUT after {limit:?}")?;
+                writeln!(self.out)
+            }
+            OutputFormat::Json => writeln!(
+                self.out,
+                r#"{{"year":{},"day":{},"part1":null,"part1_type":"Timeout","part2":null,"part2_type":"Timeout","timeout_ns":{}}}"#,
+                day.year,
+                day.day,
+                limit.as_nanos()
+            ),
+            OutputFormat::Csv => {
+                self.csv_header()?;
+                writeln!(
+                    self.out,
+                    "{},{},,Timeout,,Timeout,,,,,,,,,,",
+                    day.year, day.day
+                )
+            }
+        }
+    }
+
+    /// Lists the days that failed at the end of a run.
+    pub fn failures(&mut self, failures: &[(DayId, anyhow::Error)]) -> std::io::Result<()> {
+        if self.format != OutputFormat::Text {
+            return Ok(());
+        }
+        writeln!(self.out, "failures:")?;
+        for (day, err) in failures {
+            writeln!(self.out, "\tday {day}: {err:#}")?;
+        }
+        writeln!(self.out)
+    }
+
+    /// Prints how each part compared against the answer sheet. The verdicts go to stderr for the
+    /// machine readable formats so they don't interleave with the records.
+    pub fn check(&mut self, day: DayId, verdicts: &[(u8, Verdict)]) -> std::io::Result<()> {
+        if self.format == OutputFormat::Text {
+            writeln!(self.out, "check:")?;
+            for (part, verdict) in verdicts {
+                writeln!(self.out, "\tpart {part}: {verdict}")?;
+            }
+            writeln!(self.out)
+        } else {
+            for (part, verdict) in verdicts {
+                eprintln!("day {day} part {part}: {verdict}");
+            }
+            Ok(())
+        }
+    }
+
+    /// Ranks the days by median time, most expensive first. Only the text format has a summary.
+    pub fn summary(&mut self, runs: &[DayRun]) -> std::io::Result<()> {
+        if self.format != OutputFormat::Text || runs.is_empty() {
+            return Ok(());
+        }
+
+        let mut ranked = runs.iter().collect::<Vec<_>>();
+        ranked.sort_by_key(|run| std::cmp::Reverse(run.timing.median));
+        let total: Duration = ranked.iter().map(|run| run.timing.median).sum();
+
+        let out = &mut self.out;
+        writeln!(out, "summary:")?;
+        writeln!(out, "\trank\tday\tmedian\tmean\tp95\tshare")?;
+        for (rank, run) in ranked.into_iter().enumerate() {
+            let share = if total.is_zero() {
+                0.0
+            } else {
+                100.0 * run.timing.median.as_secs_f64() / total.as_secs_f64()
+            };
+            writeln!(
+                out,
+                "\t{}\t{}\t{:?}\t{:?}\t{:?}\t{share:.1}%",
+                rank + 1,
+                run.day,
+                run.timing.median,
+                run.timing.mean,
+                run.timing.p95,
+            )?;
+        }
+        writeln!(out, "\ttotal\t\t{total:?}")
+    }
+
+    fn write_json(&mut self, run: &DayRun) -> std::io::Result<()> {
+        let (part1, part1_type) = json_answer(run.parts.has_part1(), &run.result.part1);
+        let (part2, part2_type) = json_answer(run.parts.has_part2(), &run.result.part2);
+        let Stats {
+            runs,
+            min,
+            median,
+            mean,
+            p95,
+            stddev,
+        } = run.timing;
+        writeln!(
+            self.out,
+            r#"{{"year":{},"day":{},"part1":{part1},"part1_type":{part1_type},"part2":{part2},"part2_type":{part2_type},"elapsed_ns":{},"runs":{runs},"min_ns":{},"median_ns":{},"mean_ns":{},"p95_ns":{},"stddev_ns":{},"parse_ns":{},"part1_ns":{},"part2_ns":{}}}"#,
+            run.day.year,
+            run.day.day,
+            median.as_nanos(),
+            min.as_nanos(),
+            median.as_nanos(),
+            mean.as_nanos(),
+            p95.as_nanos(),
+            stddev.as_nanos(),
+            run.phases.parse.as_nanos(),
+            nanos_or(run.phases.part1, "null"),
+            nanos_or(run.phases.part2, "null"),
+        )
+    }
+
+    fn csv_header(&mut self) -> std::io::Result<()> {
+        if !self.wrote_header {
+            writeln!(self.out, "{CSV_HEADER}")?;
+            self.wrote_header = true;
+        }
+        Ok(())
+    }
+
+    fn write_csv(&mut self, run: &DayRun) -> std::io::Result<()> {
+        self.csv_header()?;
+
+        let (part1, part1_type) = csv_answer(run.parts.has_part1(), &run.result.part1);
+        let (part2, part2_type) = csv_answer(run.parts.has_part2(), &run.result.part2);
+        let Stats {
+            runs,
+            min,
+            median,
+            mean,
+            p95,
+            stddev,
+        } = run.timing;
+        writeln!(
+            self.out,
+            "{},{},{part1},{part1_type},{part2},{part2_type},{},{runs},{},{},{},{},{},{},{},{}",
+            run.day.year,
+            run.day.day,
+            median.as_nanos(),
+            min.as_nanos(),
+            median.as_nanos(),
+            mean.as_nanos(),
+            p95.as_nanos(),
+            stddev.as_nanos(),
+            run.phases.parse.as_nanos(),
+            nanos_or(run.phases.part1, ""),
+            nanos_or(run.phases.part2, ""),
+        )
+    }
+}
+
+fn nanos_or(duration: Option<Duration>, missing: &str) -> String {
+    duration.map_or_else(|| missing.to_string(), |d| d.as_nanos().to_string())
+}
+
+/// The type reported for a part that wasn't selected, to tell it apart from one with no answer.
+const SKIPPED: &str = "Skipped";
+
+fn json_answer(selected: bool, answer: &Option<Answers>) -> (String, String) {
+    match answer {
+        _ if !selected => ("null".to_string(), json_string(SKIPPED)),
+        Some(answer) => (
+            json_string(&answer.to_string()),
+            json_string(answer.variant_name()),
+        ),
+        None => ("null".to_string(), "null".to_string()),
+    }
+}
+
+fn csv_answer(selected: bool, answer: &Option<Answers>) -> (String, String) {
+    match answer {
+        _ if !selected => (String::new(), SKIPPED.to_string()),
+        Some(answer) => (
+            csv_field(&answer.to_string()),
+            csv_field(answer.variant_name()),
+        ),
+        None => (String::new(), String::new()),
+    }
+}
+
+/// Quotes `s` as a JSON string, escaping quotes, backslashes and control characters.
+pub fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Quotes `s` as an RFC 4180 field if it contains a separator, quote or line break.
+pub fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{csv_field, json_string, OutputFormat, Reporter, CSV_HEADER};
+    use crate::timing::Stats;
+    use crate::{DayId, DayResult, DayRun, Parts, PhaseTimes};
+    use std::time::Duration;
+
+    fn unquote_json(s: &str) -> String {
+        let mut chars = s[1..s.len() - 1].chars();
+        let mut out = String::new();
+        while let Some(c) = chars.next() {
+            if c != '\\' {
+                out.push(c);
+                continue;
+            }
+            match chars.next().unwrap() {
+                'n' => out.push('\n'),
+                'r' => out.push('\r'),
+                't' => out.push('\t'),
+                'u' => {
+                    let hex = chars.by_ref().take(4).collect::<String>();
+                    out.push(char::from_u32(u32::from_str_radix(&hex, 16).unwrap()).unwrap());
+                }
+                c => out.push(c),
+            }
+        }
+        out
+    }
+
+    fn unquote_csv(s: &str) -> String {
+        match s.strip_prefix('"').and_then(|s| s.strip_suffix('"')) {
+            Some(inner) => inner.replace("\"\"", "\""),
+            None => s.to_string(),
+        }
+    }
+
+    #[test]
+    fn multi_line_answers_round_trip() {
+        let answers = ["# #\n ##\n", "say \"hi\"\\", "a,b\r\n\u{1}", "plain"];
+        for answer in answers {
+            assert_eq!(unquote_json(&json_string(answer)), answer);
+            assert_eq!(unquote_csv(&csv_field(answer)), answer);
+        }
+        assert_eq!(json_string("#\n#"), "\"#\\n#\"");
+        assert_eq!(csv_field("#\n#"), "\"#\n#\"");
+    }
+
+    #[test]
+    fn records_include_types_and_duration() {
+        let run = DayRun {
+            day: DayId::new(2022, 3),
+            parts: Parts::Both,
+            result: DayResult {
+                part1: Some(157_u64.into()),
+                part2: None,
+            },
+            timing: Stats::from_samples(&[Duration::from_nanos(1_500)]).unwrap(),
+            phases: PhaseTimes {
+                parse: Duration::from_nanos(1_000),
+                part1: Some(Duration::from_nanos(400)),
+                part2: None,
+            },
+        };
+
+        let mut json = Vec::new();
+        Reporter::new(OutputFormat::Json, &mut json)
+            .report(&run)
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(json).unwrap(),
+            "{\"year\":2022,\"day\":3,\"part1\":\"157\",\"part1_type\":\"U64\",\"part2\":null,\"part2_type\":null,\"elapsed_ns\":1500,\"runs\":1,\"min_ns\":1500,\"median_ns\":1500,\"mean_ns\":1500,\"p95_ns\":1500,\"stddev_ns\":0,\"parse_ns\":1000,\"part1_ns\":400,\"part2_ns\":null}\n"
+        );
+
+        let mut csv = Vec::new();
+        let mut reporter = Reporter::new(OutputFormat::Csv, &mut csv);
+        reporter.report(&run).unwrap();
+        reporter.report(&run).unwrap();
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            format!(
+                "{CSV_HEADER}\n{}",
+                "2022,3,157,U64,,,1500,1,1500,1500,1500,1500,0,1000,400,\n".repeat(2)
+            )
+        );
+    }
+
+    #[test]
+    fn failures_are_reported() {
+        let err = anyhow::anyhow!("panicked: \"oops\"");
+
+        let mut json = Vec::new();
+        Reporter::new(OutputFormat::Json, &mut json)
+            .failure(DayId::new(2022, 21), &err)
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(json).unwrap(),
+            "{\"year\":2022,\"day\":21,\"error\":\"panicked: \\\"oops\\\"\"}\n"
+        );
+
+        let mut text = Vec::new();
+        Reporter::new(OutputFormat::Text, &mut text)
+            .failure(DayId::new(2022, 21), &err)
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(text).unwrap(),
+            "day 2022/21:\nFAILED:\n\tpanicked: \"oops\"\n\n"
+        );
+    }
+
+    #[test]
+    fn timeouts_are_reported() {
+        let mut csv = Vec::new();
+        Reporter::new(OutputFormat::Csv, &mut csv)
+            .timeout(DayId::new(2022, 16), Duration::from_secs(5))
+            .unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        let row = csv.lines().nth(1).unwrap();
+        assert_eq!(row, "2022,16,,Timeout,,Timeout,,,,,,,,,,");
+        assert_eq!(row.split(',').count(), CSV_HEADER.split(',').count());
+    }
+
+    #[test]
+    fn skipped_parts_are_reported() {
+        let run = DayRun {
+            day: DayId::new(2022, 16),
+            parts: Parts::Part2,
+            result: DayResult {
+                part1: None,
+                part2: Some(2304_u64.into()),
+            },
+            timing: Stats::from_samples(&[Duration::from_nanos(1_500)]).unwrap(),
+            phases: PhaseTimes {
+                parse: Duration::from_nanos(1_000),
+                part1: None,
+                part2: Some(Duration::from_nanos(500)),
+            },
+        };
+
+        let mut text = Vec::new();
+        Reporter::new(OutputFormat::Text, &mut text)
+            .report(&run)
+            .unwrap();
+        assert!(String::from_utf8(text)
+            .unwrap()
+            .starts_with("day 2022/16:\npart 1:\n\tskipped\npart 2:\n\t2304\n"));
+
+        let mut csv = Vec::new();
+        Reporter::new(OutputFormat::Csv, &mut csv)
+            .report(&run)
+            .unwrap();
+        assert!(String::from_utf8(csv)
+            .unwrap()
+            .ends_with("\n2022,16,,Skipped,2304,U64,1500,1,1500,1500,1500,1500,0,1000,,500\n"));
+    }
+}
@@ -0,0 +1,380 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use anyhow::{bail, Context};
+use thiserror::Error;
+use toml::{Table, Value};
+
+use crate::DayId;
+
+/// A tunable constant of a day's puzzle, with the values the puzzle uses for the real and the
+/// example inputs.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Param {
+    pub name: &'static str,
+    pub real: i64,
+    pub test: i64,
+}
+
+impl Param {
+    /// A parameter that's the same for the real and example inputs.
+    pub const fn new(name: &'static str, value: i64) -> Param {
+        Param {
+            name,
+            real: value,
+            test: value,
+        }
+    }
+}
+
+/// The parameter values a day is run with.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct DayParams {
+    values: Vec<(&'static str, i64)>,
+}
+
+impl DayParams {
+    /// The puzzle's own values for `params`.
+    pub fn defaults(params: &[Param], is_test: bool) -> DayParams {
+        DayParams {
+            values: params
+                .iter()
+                .map(|param| (param.name, if is_test { param.test } else { param.real }))
+                .collect(),
+        }
+    }
+
+    pub fn set(&mut self, name: &str, value: i64) -> Result<(), ParamError> {
+        match self.values.iter_mut().find(|(known, _)| *known == name) {
+            Some((_, slot)) => {
+                *slot = value;
+                Ok(())
+            }
+            None => Err(ParamError::Unknown(name.to_string())),
+        }
+    }
+
+    /// The value of `name`, converted to whatever the day needs.
+    pub fn get<T: TryFrom<i64>>(&self, name: &str) -> Result<T, ParamError> {
+        let &(_, value) = self
+            .values
+            .iter()
+            .find(|(known, _)| *known == name)
+            .ok_or_else(|| ParamError::Unknown(name.to_string()))?;
+        T::try_from(value).map_err(|_| ParamError::OutOfRange {
+            name: name.to_string(),
+            value,
+        })
+    }
+}
+
+/// Parameter values given on the command line or in a config file, either for every day that
+/// has a parameter of that name or for one day only.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct ParamOverrides {
+    every_day: BTreeMap<String, i64>,
+    // keyed by year and day, where overrides given without a year are for the latest one
+    days: BTreeMap<(Option<u32>, u32), BTreeMap<String, i64>>,
+    latest_year: u32,
+}
+
+impl ParamOverrides {
+    /// Adds an override written as `name=value`, `day.name=value` or `year/day.name=value`, e.g.
+    /// `15.row=10` or `2022/15.row=10`.
+    pub fn add(&mut self, arg: &str) -> Result<(), ParamError> {
+        let invalid = || ParamError::Invalid(arg.to_string());
+        let (key, value) = arg.split_once('=').ok_or_else(invalid)?;
+        let value = value.trim().parse().map_err(|_| invalid())?;
+        match key.split_once('.') {
+            Some((day, name)) => {
+                let (year, day) = match day.split_once('/') {
+                    Some((year, day)) => (Some(year.parse().map_err(|_| invalid())?), day),
+                    None => (None, day),
+                };
+                let day = day.parse().map_err(|_| invalid())?;
+                self.days
+                    .entry((year, day))
+                    .or_default()
+                    .insert(name.to_string(), value);
+            }
+            None => {
+                self.every_day.insert(key.to_string(), value);
+            }
+        }
+        Ok(())
+    }
+
+    /// Loads overrides from a TOML file, where top level values apply to every day, tables named
+    /// like `[day15]` to just that day of the latest year, and ones like `[y2021.day15]` to that
+    /// day of another year.
+    pub fn load(path: &Path) -> anyhow::Result<ParamOverrides> {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        ParamOverrides::parse(&contents)
+            .with_context(|| format!("failed to parse {}", path.display()))
+    }
+
+    pub fn parse(contents: &str) -> anyhow::Result<ParamOverrides> {
+        let table: Table = contents.parse()?;
+        let mut overrides = ParamOverrides::default();
+        for (key, value) in table {
+            match value {
+                Value::Integer(value) => {
+                    overrides.every_day.insert(key, value);
+                }
+                Value::Table(table) => match key.strip_prefix('y').map(str::parse) {
+                    Some(Ok(year)) => {
+                        for (day, params) in table {
+                            let Value::Table(params) = params else {
+                                bail!("expected {key}.{day} to be a table");
+                            };
+                            overrides.add_table(Some(year), &day, params)?;
+                        }
+                    }
+                    _ => overrides.add_table(None, &key, table)?,
+                },
+                _ => bail!("expected {key} to be an integer or a table"),
+            }
+        }
+        Ok(overrides)
+    }
+
+    /// Adds the overrides in a table named like `day15`.
+    fn add_table(&mut self, year: Option<u32>, key: &str, params: Table) -> anyhow::Result<()> {
+        let Some(day) = key.strip_prefix("day").and_then(|day| day.parse().ok()) else {
+            bail!("expected a table named like day15 or y2021, found {key:?}");
+        };
+        for (name, value) in params {
+            let Value::Integer(value) = value else {
+                bail!("expected {key}.{name} to be an integer");
+            };
+            self.days
+                .entry((year, day))
+                .or_default()
+                .insert(name, value);
+        }
+        Ok(())
+    }
+
+    /// Sets the year that overrides given without one are for, which should be the latest year
+    /// there are days for, as with days picked to run.
+    pub fn set_latest_year(&mut self, year: u32) {
+        self.latest_year = year;
+    }
+
+    /// The day an override given for `(year, day)` is for.
+    fn day_id(&self, (year, day): (Option<u32>, u32)) -> DayId {
+        DayId::new(year.unwrap_or(self.latest_year), day)
+    }
+
+    /// Layers `other` on top of these overrides.
+    pub fn extend(&mut self, other: ParamOverrides) {
+        self.every_day.extend(other.every_day);
+        for (day, params) in other.days {
+            self.days.entry(day).or_default().extend(params);
+        }
+    }
+
+    /// The parameters for `day`: its defaults, then any overrides for every day that it has,
+    /// then the overrides just for it, with the ones that name its year last.
+    pub fn resolve(
+        &self,
+        day: DayId,
+        params: &[Param],
+        is_test: bool,
+    ) -> Result<DayParams, ParamError> {
+        let mut values = DayParams::defaults(params, is_test);
+        for (name, &value) in &self.every_day {
+            // not every day has every parameter
+            let _ = values.set(name, value);
+        }
+        let latest = (day.year == self.latest_year).then_some((None, day.day));
+        let keys = latest.into_iter().chain([(Some(day.year), day.day)]);
+        for (name, &value) in keys.filter_map(|key| self.days.get(&key)).flatten() {
+            values
+                .set(name, value)
+                .map_err(|_| ParamError::UnknownForDay {
+                    day,
+                    name: name.clone(),
+                })?;
+        }
+        Ok(values)
+    }
+
+    /// Checks that every override names a parameter of some day in `days`.
+    pub fn validate<'a>(
+        &self,
+        days: impl IntoIterator<Item = (DayId, &'a [Param])>,
+    ) -> Result<(), ParamError> {
+        let days = days.into_iter().collect::<Vec<_>>();
+        for name in self.every_day.keys() {
+            let known = days
+                .iter()
+                .any(|(_, params)| params.iter().any(|param| param.name == name));
+            if !known {
+                return Err(ParamError::Unknown(name.clone()));
+            }
+        }
+        for (&key, names) in &self.days {
+            let day = self.day_id(key);
+            let known = |name: &str| {
+                days.iter()
+                    .filter(|&&(known, _)| known == day)
+                    .any(|(_, params)| params.iter().any(|param| param.name == name))
+            };
+            if let Some(name) = names.keys().find(|name| !known(name)) {
+                return Err(ParamError::UnknownForDay {
+                    day,
+                    name: name.clone(),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Error, Eq, PartialEq)]
+pub enum ParamError {
+    #[error(
+        "Expected a parameter like name=value, day.name=value or year/day.name=value, found {0:?}"
+    )]
+    Invalid(String),
+    #[error("No day has a parameter called {0:?}")]
+    Unknown(String),
+    #[error("Day {day} has no parameter called {name:?}")]
+    UnknownForDay { day: DayId, name: String },
+    #[error("Parameter {name} = {value} is out of range")]
+    OutOfRange { name: String, value: i64 },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DayParams, Param, ParamError, ParamOverrides};
+    use crate::DayId;
+
+    const PARAMS: &[Param] = &[
+        Param {
+            name: "row",
+            real: 2_000_000,
+            test: 10,
+        },
+        Param::new("minutes", 30),
+    ];
+
+    #[test]
+    fn defaults_follow_the_input() {
+        let real = DayParams::defaults(PARAMS, false);
+        let test = DayParams::defaults(PARAMS, true);
+        assert_eq!(real.get::<i64>("row"), Ok(2_000_000));
+        assert_eq!(test.get::<i64>("row"), Ok(10));
+        assert_eq!(test.get::<u64>("minutes"), Ok(30));
+        assert_eq!(test.get::<u8>("row"), Ok(10));
+        assert_eq!(
+            real.get::<u8>("row"),
+            Err(ParamError::OutOfRange {
+                name: "row".to_string(),
+                value: 2_000_000
+            })
+        );
+        assert_eq!(
+            real.get::<u64>("rows"),
+            Err(ParamError::Unknown("rows".to_string()))
+        );
+    }
+
+    #[test]
+    fn overrides_are_layered() {
+        let mut overrides = ParamOverrides::parse("minutes = 20\n[day15]\nrow = 11\n").unwrap();
+        let mut cli = ParamOverrides::default();
+        cli.add("15.minutes=5").unwrap();
+        overrides.extend(cli);
+        overrides.set_latest_year(2022);
+
+        let params = overrides
+            .resolve(DayId::new(2022, 15), PARAMS, true)
+            .unwrap();
+        assert_eq!(params.get::<i64>("row"), Ok(11));
+        assert_eq!(params.get::<i64>("minutes"), Ok(5));
+
+        let params = overrides
+            .resolve(DayId::new(2022, 16), PARAMS, false)
+            .unwrap();
+        assert_eq!(params.get::<i64>("row"), Ok(2_000_000));
+        assert_eq!(params.get::<i64>("minutes"), Ok(20));
+
+        // days without the parameter ignore overrides meant for every day
+        assert_eq!(
+            overrides.resolve(DayId::new(2022, 1), &[], false),
+            Ok(DayParams::default())
+        );
+        assert!(overrides.validate([(DayId::new(2022, 15), PARAMS)]).is_ok());
+    }
+
+    #[test]
+    fn bad_overrides_are_rejected() {
+        let mut overrides = ParamOverrides::default();
+        assert_eq!(
+            overrides.add("row"),
+            Err(ParamError::Invalid("row".to_string()))
+        );
+        assert_eq!(
+            overrides.add("x.row=1"),
+            Err(ParamError::Invalid("x.row=1".to_string()))
+        );
+
+        overrides.add("rows=1").unwrap();
+        assert_eq!(
+            overrides.validate([(DayId::new(2022, 15), PARAMS)]),
+            Err(ParamError::Unknown("rows".to_string()))
+        );
+
+        let mut overrides = ParamOverrides::default();
+        overrides.add("3.row=1").unwrap();
+        overrides.set_latest_year(2022);
+        assert_eq!(
+            overrides.resolve(DayId::new(2022, 3), &[], false),
+            Err(ParamError::UnknownForDay {
+                day: DayId::new(2022, 3),
+                name: "row".to_string()
+            })
+        );
+        assert_eq!(
+            overrides.add("x/3.row=1"),
+            Err(ParamError::Invalid("x/3.row=1".to_string()))
+        );
+    }
+
+    #[test]
+    fn overrides_know_the_year() {
+        const OLD_PARAMS: &[Param] = &[Param::new("steps", 6)];
+        let days = [
+            (DayId::new(2021, 15), OLD_PARAMS),
+            (DayId::new(2022, 15), PARAMS),
+        ];
+        let mut overrides =
+            ParamOverrides::parse("[day15]\nrow = 11\n[y2021.day15]\nsteps = 7\n").unwrap();
+        overrides.add("2021/15.steps=8").unwrap();
+        overrides.set_latest_year(2022);
+        assert!(overrides.validate(days).is_ok());
+
+        // a day without a year is the latest year's, and doesn't touch the same day of another
+        let params = overrides
+            .resolve(DayId::new(2022, 15), PARAMS, false)
+            .unwrap();
+        assert_eq!(params.get::<i64>("row"), Ok(11));
+        let params = overrides
+            .resolve(DayId::new(2021, 15), OLD_PARAMS, false)
+            .unwrap();
+        assert_eq!(params.get::<i64>("steps"), Ok(8));
+
+        overrides.add("15.steps=1").unwrap();
+        assert_eq!(
+            overrides.validate(days),
+            Err(ParamError::UnknownForDay {
+                day: DayId::new(2022, 15),
+                name: "steps".to_string()
+            })
+        );
+        assert!(ParamOverrides::parse("[y2021]\nsteps = 7\n").is_err());
+    }
+}
@@ -0,0 +1,102 @@
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Mutex};
+
+/// Runs `f` over `jobs` on up to `threads` worker threads, handing each result to `done` in the
+/// order the jobs were given, as soon as it and every job before it have finished.
+///
+/// If `done` returns an error no more jobs are started, and the error is returned once the jobs
+/// already running have finished.
+pub fn run_in_order<J, R, E>(
+    threads: usize,
+    jobs: Vec<J>,
+    f: impl Fn(J) -> R + Sync,
+    mut done: impl FnMut(R) -> Result<(), E>,
+) -> Result<(), E>
+where
+    J: Send,
+    R: Send,
+{
+    let threads = threads.clamp(1, jobs.len().max(1));
+    let queue = Mutex::new(jobs.into_iter().enumerate());
+    let stop = AtomicBool::new(false);
+    let (tx, rx) = mpsc::channel();
+
+    std::thread::scope(|scope| {
+        for _ in 0..threads {
+            let tx = tx.clone();
+            let (queue, stop, f) = (&queue, &stop, &f);
+            scope.spawn(move || {
+                while !stop.load(Ordering::Relaxed) {
+                    let Some((index, job)) = queue.lock().unwrap().next() else {
+                        break;
+                    };
+                    if tx.send((index, f(job))).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(tx);
+
+        let mut finished = BTreeMap::new();
+        let mut next = 0;
+        for (index, result) in rx {
+            finished.insert(index, result);
+            while let Some(result) = finished.remove(&next) {
+                next += 1;
+                if let Err(err) = done(result) {
+                    stop.store(true, Ordering::Relaxed);
+                    return Err(err);
+                }
+            }
+        }
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::run_in_order;
+    use std::time::Duration;
+
+    #[test]
+    fn results_come_back_in_order() {
+        let mut seen = Vec::new();
+        let result = run_in_order::<_, _, ()>(
+            4,
+            (0..20_u64).collect(),
+            |job| {
+                // later jobs finish first
+                std::thread::sleep(Duration::from_millis(20 - job));
+                job * 2
+            },
+            |result| {
+                seen.push(result);
+                Ok(())
+            },
+        );
+        assert_eq!(result, Ok(()));
+        assert_eq!(seen, (0..20).map(|job| job * 2).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn errors_stop_new_jobs() {
+        let mut seen = Vec::new();
+        let result = run_in_order(
+            1,
+            (0..100).collect(),
+            |job| job,
+            |result| {
+                seen.push(result);
+                if result == 3 {
+                    Err("stop")
+                } else {
+                    Ok(())
+                }
+            },
+        );
+        assert_eq!(result, Err("stop"));
+        assert_eq!(seen, vec![0, 1, 2, 3]);
+    }
+}
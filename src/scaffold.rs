@@ -0,0 +1,229 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, ensure, Context};
+
+use crate::DayId;
+
+const TEMPLATE: &str = include_str!("../templates/day.rs");
+
+/// The module of a year that has no days yet.
+const YEAR_TEMPLATE: &str = "register_days! {
+    year: {year},
+}
+";
+
+/// Parses the day given to `new`, either `day` in `latest_year` or `year/day`.
+pub fn parse_day(arg: &str, latest_year: u32) -> anyhow::Result<DayId> {
+    let invalid = || format!("expected a day like 5 or 2023/5, found {arg:?}");
+    let (year, day) = match arg.split_once('/') {
+        Some((year, day)) => (year.parse().with_context(invalid)?, day),
+        None => (latest_year, arg),
+    };
+    let day = day.parse().with_context(invalid)?;
+    ensure!((1..=25).contains(&day), "day {day} isn't part of an event");
+    Ok(DayId::new(year, day))
+}
+
+/// Creates the module for a new day from the template along with empty inputs for it, and
+/// registers it, adding its year too if it's the first day of it. `root` is the crate's
+/// directory, and is checked to be one. Returns every file created or changed.
+pub fn new_day(
+    root: &Path,
+    DayId { year, day }: DayId,
+    title: &str,
+) -> anyhow::Result<Vec<PathBuf>> {
+    let days_dir = root.join("src").join("days");
+    let year_dir = days_dir.join(format!("y{year}"));
+    let module_path = year_dir.join(format!("day{day:02}.rs"));
+    let year_path = year_dir.join("mod.rs");
+
+    ensure!(
+        days_dir.join("mod.rs").is_file(),
+        "{} isn't the crate's directory, as it has no src/days/mod.rs",
+        root.display()
+    );
+    ensure!(
+        !module_path.exists(),
+        "{} already exists",
+        module_path.display()
+    );
+    let mut written = Vec::new();
+
+    let year_module = if year_path.exists() {
+        read(&year_path)?
+    } else {
+        let days_path = days_dir.join("mod.rs");
+        let days = add_year(&read(&days_path)?, year)
+            .with_context(|| format!("couldn't add {year} to {}", days_path.display()))?;
+        std::fs::create_dir_all(&year_dir)
+            .with_context(|| format!("failed to create {}", year_dir.display()))?;
+        write(&days_path, &days)?;
+        written.push(days_path);
+        YEAR_TEMPLATE.replace("{year}", &year.to_string())
+    };
+    let year_module = add_day(&year_module, day, title)
+        .with_context(|| format!("couldn't add day {day} to {}", year_path.display()))?;
+
+    let day_name = format!("{day:02}");
+    write(
+        &module_path,
+        &TEMPLATE
+            .replace("{year}", &year.to_string())
+            .replace("{day}", &day_name),
+    )?;
+    written.push(module_path);
+    write(&year_path, &year_module)?;
+    written.push(year_path);
+
+    for kind in ["real", "test"] {
+        let dir = root.join("input").join(year.to_string()).join(kind);
+        let path = dir.join(format!("{day_name}.txt"));
+        if !path.exists() {
+            std::fs::create_dir_all(&dir)
+                .with_context(|| format!("failed to create {}", dir.display()))?;
+            write(&path, "")?;
+            written.push(path);
+        }
+    }
+
+    Ok(written)
+}
+
+/// Adds `day` to the `register_days!` list of its year, before the first day after it.
+fn add_day(module: &str, day: u32, title: &str) -> anyhow::Result<String> {
+    let registered = |line: &str| {
+        let number = line.strip_prefix("    ")?.split_once(" => day")?.0;
+        number.parse::<u32>().ok()
+    };
+    let mut at = None;
+    let mut offset = 0;
+    for line in module.split_inclusive('\n') {
+        match registered(line) {
+            Some(other) if other == day => bail!("day {day} is already registered"),
+            Some(other) if other > day => {
+                at = Some(offset);
+                break;
+            }
+            _ => {}
+        }
+        offset += line.len();
+    }
+    let Some(at) = at.or_else(|| module.rfind("}\n")) else {
+        bail!("couldn't find the end of register_days!");
+    };
+
+    let entry = format!(
+        "    {day:02} => day{day:02} {{
+        title: {title:?},
+        solver: crate::run_phases::<day{day:02}::Day{day:02}>,
+        params: day{day:02}::PARAMS,
+    }},
+"
+    );
+    Ok(format!("{}{entry}{}", &module[..at], &module[at..]))
+}
+
+/// Declares `year`'s module and adds its days to the registry.
+fn add_year(module: &str, year: u32) -> anyhow::Result<String> {
+    let declaration = format!("pub mod y{year};");
+    let Some(last) = module
+        .lines()
+        .rev()
+        .find(|line| line.starts_with("pub mod y"))
+    else {
+        bail!("couldn't find where the years are declared");
+    };
+    let at = module.find(last).expect("line is in the module") + last.len() + 1;
+    let module = format!("{}{declaration}\n{}", &module[..at], &module[at..]);
+
+    let Some(end) = module.find("];\n    years.into_iter()") else {
+        bail!("couldn't find the list of years");
+    };
+    let years = module[..end].trim_end();
+    let separator = if years.ends_with(',') { " " } else { ", " };
+    Ok(format!(
+        "{years}{separator}y{year}::get_days(){}",
+        &module[end..]
+    ))
+}
+
+fn read(path: &Path) -> anyhow::Result<String> {
+    std::fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))
+}
+
+fn write(path: &Path, contents: &str) -> anyhow::Result<()> {
+    std::fs::write(path, contents).with_context(|| format!("failed to write {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{new_day, parse_day};
+    use crate::DayId;
+    use std::path::{Path, PathBuf};
+
+    /// A scratch directory that's deleted again however the test ends.
+    struct TempDir(PathBuf);
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn days_are_parsed() {
+        assert_eq!(parse_day("5", 2022).unwrap(), DayId::new(2022, 5));
+        assert_eq!(parse_day("2023/12", 2022).unwrap(), DayId::new(2023, 12));
+        assert!(parse_day("26", 2022).is_err());
+        assert!(parse_day("x/1", 2022).is_err());
+    }
+
+    #[test]
+    fn new_days_are_registered() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _cleanup = TempDir(root.clone());
+        std::fs::create_dir_all(&root).unwrap();
+        assert!(new_day(&root, DayId::new(2022, 25), "").is_err());
+        assert_eq!(std::fs::read_dir(&root).unwrap().count(), 0);
+
+        let days = root.join("src").join("days");
+        std::fs::create_dir_all(days.join("y2022")).unwrap();
+        std::fs::write(days.join("mod.rs"), include_str!("days/mod.rs")).unwrap();
+        std::fs::write(
+            days.join("y2022").join("mod.rs"),
+            include_str!("days/y2022/mod.rs"),
+        )
+        .unwrap();
+        let read = |path: &Path| std::fs::read_to_string(root.join(path)).unwrap();
+
+        let written = new_day(&root, DayId::new(2022, 25), "Full of Hot Air").unwrap();
+        assert_eq!(written.len(), 4);
+        let year = read(Path::new("src/days/y2022/mod.rs"));
+        assert!(year.ends_with(
+            "        slow: true,
+    },
+    25 => day25 {
+        title: \"Full of Hot Air\",
+        solver: crate::run_phases::<day25::Day25>,
+        params: day25::PARAMS,
+    },
+}
+"
+        ));
+        let day = read(Path::new("src/days/y2022/day25.rs"));
+        assert!(day.contains("pub struct Day25;"));
+        assert!(day.contains("include_str!(\"../../../input/2022/test/25.txt\")"));
+        assert_eq!(read(Path::new("input/2022/real/25.txt")), "");
+        assert!(new_day(&root, DayId::new(2022, 25), "").is_err());
+
+        // a new year, with its days added out of order
+        assert_eq!(new_day(&root, DayId::new(2023, 5), "").unwrap().len(), 5);
+        assert_eq!(new_day(&root, DayId::new(2023, 2), "").unwrap().len(), 4);
+        let days = read(Path::new("src/days/mod.rs"));
+        assert!(days.contains("pub mod y2022;\npub mod y2023;\n"));
+        assert!(days.contains("let years = [y2022::get_days(), y2023::get_days()];\n"));
+        let year = read(Path::new("src/days/y2023/mod.rs"));
+        assert!(year.starts_with("register_days! {\n    year: 2023,\n    02 => day02 {\n"));
+        assert!(year.contains("    05 => day05 {\n"));
+    }
+}
@@ -0,0 +1,329 @@
+//! Shortest path searches over graphs that are only known through a neighbour function.
+//!
+//! States can be anything hashable, the search can set out from any number of starts at once, and
+//! it finishes as soon as it takes a state the goal predicate accepts. Every state reached keeps
+//! the state it was reached from, so the cheapest path comes back along with its cost.
+
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+use fxhash::FxHashMap;
+use num::Zero;
+
+/// What a search found, and how much work it took.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Search<S, C> {
+    /// The cheapest way to a goal, if any goal could be reached.
+    pub found: Option<Found<S, C>>,
+    pub stats: SearchStats,
+}
+
+/// The cheapest path to a goal.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Found<S, C> {
+    pub cost: C,
+    /// From the start it set out from to the goal, both included.
+    pub path: Vec<S>,
+}
+
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub struct SearchStats {
+    /// States whose neighbours were looked at.
+    pub expanded: usize,
+    /// Distinct states reached, starts included.
+    pub discovered: usize,
+}
+
+impl<S, C: Copy> Search<S, C> {
+    pub fn cost(&self) -> Option<C> {
+        self.found.as_ref().map(|found| found.cost)
+    }
+}
+
+const NO_PARENT: usize = usize::MAX;
+
+/// Every state a search has reached, with how it got there.
+struct Nodes<S, C> {
+    nodes: Vec<Node<S, C>>,
+    index: FxHashMap<S, usize>,
+    stats: SearchStats,
+}
+
+struct Node<S, C> {
+    state: S,
+    parent: usize,
+    cost: C,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Nodes<S, C> {
+    fn new() -> Nodes<S, C> {
+        Nodes {
+            nodes: Vec::new(),
+            index: FxHashMap::default(),
+            stats: SearchStats::default(),
+        }
+    }
+
+    fn push(&mut self, state: S, parent: usize, cost: C) -> usize {
+        let node = self.nodes.len();
+        self.nodes.push(Node {
+            state,
+            parent,
+            cost,
+        });
+        self.stats.discovered += 1;
+        node
+    }
+
+    fn finish(self, goal: Option<usize>) -> Search<S, C> {
+        let found = goal.map(|goal| {
+            let mut path = Vec::new();
+            let mut node = goal;
+            while node != NO_PARENT {
+                path.push(self.nodes[node].state.clone());
+                node = self.nodes[node].parent;
+            }
+            path.reverse();
+            Found {
+                cost: self.nodes[goal].cost,
+                path,
+            }
+        });
+        Search {
+            found,
+            stats: self.stats,
+        }
+    }
+}
+
+/// The fewest steps from any of `starts` to a goal, where every step costs the same.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut nodes = Nodes::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if let Entry::Vacant(entry) = nodes.index.entry(start.clone()) {
+            entry.insert(nodes.nodes.len());
+            queue.push_back(nodes.push(start, NO_PARENT, 0));
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        let Node { state, cost, .. } = &nodes.nodes[node];
+        if is_goal(state) {
+            return nodes.finish(Some(node));
+        }
+        let cost = cost + 1;
+        nodes.stats.expanded += 1;
+
+        for next in neighbours(state) {
+            if let Entry::Vacant(entry) = nodes.index.entry(next.clone()) {
+                entry.insert(nodes.nodes.len());
+                queue.push_back(nodes.push(next, node, cost));
+            }
+        }
+    }
+
+    nodes.finish(None)
+}
+
+/// The cheapest way from any of `starts` to a goal, where `neighbours` gives each next state with
+/// what it costs to step there.
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    neighbours: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Zero,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(starts, neighbours, |_| C::zero(), is_goal)
+}
+
+/// [`dijkstra`], trying first the states that `heuristic` thinks are closest to a goal. The
+/// heuristic must never guess more than the real cost, or the path found may not be the cheapest.
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Zero,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut nodes = Nodes::new();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        if let Entry::Vacant(entry) = nodes.index.entry(start.clone()) {
+            entry.insert(nodes.nodes.len());
+            let estimate = heuristic(&start);
+            let node = nodes.push(start, NO_PARENT, C::zero());
+            queue.push(Queued {
+                estimate,
+                cost: C::zero(),
+                node,
+            });
+        }
+    }
+
+    while let Some(Queued { cost, node, .. }) = queue.pop() {
+        // it's been queued again since, more cheaply
+        if cost > nodes.nodes[node].cost {
+            continue;
+        }
+        let state = &nodes.nodes[node].state;
+        if is_goal(state) {
+            return nodes.finish(Some(node));
+        }
+        nodes.stats.expanded += 1;
+
+        for (next, step) in neighbours(state) {
+            let next_cost = cost + step;
+            let next_node = match nodes.index.entry(next) {
+                Entry::Occupied(entry) => {
+                    let known = &mut nodes.nodes[*entry.get()];
+                    if known.cost <= next_cost {
+                        continue;
+                    }
+                    known.cost = next_cost;
+                    known.parent = node;
+                    *entry.get()
+                }
+                Entry::Vacant(entry) => {
+                    let next = entry.key().clone();
+                    entry.insert(nodes.nodes.len());
+                    nodes.push(next, node, next_cost)
+                }
+            };
+            queue.push(Queued {
+                estimate: next_cost + heuristic(&nodes.nodes[next_node].state),
+                cost: next_cost,
+                node: next_node,
+            });
+        }
+    }
+
+    nodes.finish(None)
+}
+
+/// A node waiting to be expanded, which comes out of the heap sooner the lower its estimate. Ties
+/// go to whichever has come further, as it's likely nearer a goal.
+#[derive(Eq, PartialEq)]
+struct Queued<C> {
+    estimate: C,
+    cost: C,
+    node: usize,
+}
+
+impl<C: Ord> PartialOrd for Queued<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C: Ord> Ord for Queued<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{astar, bfs, dijkstra};
+    use crate::geom::Point2;
+    use crate::grid::Grid;
+
+    const MAZE: &str = "\
+S.#.....
+.##.###.
+....#...
+.##...#E
+";
+
+    fn maze() -> Grid<u8> {
+        Grid::parse(MAZE, "a maze", Some).unwrap()
+    }
+
+    fn find(maze: &Grid<u8>, tile: u8) -> Point2<i64> {
+        let at = maze.position(|&b| b == tile).unwrap();
+        Point2::new(at.x as i64, at.y as i64)
+    }
+
+    #[test]
+    fn searches_find_the_shortest_path() {
+        let maze = maze();
+        let open = |p: &Point2<i64>| {
+            let neighbours = p.neighbours4().into_iter();
+            neighbours.filter(|&n| maze.get(n).is_some_and(|&b| b != b'#'))
+        };
+        let is_goal = |p: &Point2<i64>| maze[*p] == b'E';
+        let start = find(&maze, b'S');
+
+        let found = bfs([start], open, is_goal);
+        assert_eq!(found.cost(), Some(12));
+        let path = found.found.unwrap().path;
+        assert_eq!(path.len(), 13);
+        assert_eq!(path[0], start);
+        assert_eq!(maze[path[12]], b'E');
+        assert!(path.windows(2).all(|w| w[0].manhattan(&w[1]) == 1));
+
+        let weighted = |p: &Point2<i64>| open(p).map(|n| (n, 1));
+        let slow = dijkstra([start], weighted, is_goal);
+        let goal = find(&maze, b'E');
+        let fast = astar([start], weighted, |p| p.manhattan(&goal), is_goal);
+        assert_eq!(slow.cost(), Some(12));
+        assert_eq!(fast.cost(), Some(12));
+        assert!(fast.stats.expanded < slow.stats.expanded);
+    }
+
+    #[test]
+    fn searches_can_start_anywhere_or_find_nothing() {
+        let maze = maze();
+        let open = |p: &Point2<i64>| {
+            let neighbours = p.neighbours4().into_iter();
+            neighbours.filter(|&n| maze.get(n).is_some_and(|&b| b != b'#'))
+        };
+        let is_goal = |p: &Point2<i64>| maze[*p] == b'E';
+
+        let starts = [Point2::new(0, 0), Point2::new(7, 0)];
+        let found = bfs(starts, open, is_goal).found.unwrap();
+        assert_eq!(found.cost, 3);
+        assert_eq!(found.path[0], starts[1]);
+
+        let walled_in = bfs([Point2::new(0, 0)], |_| [], is_goal);
+        assert_eq!(walled_in.found, None);
+        assert_eq!(walled_in.stats.expanded, 1);
+        assert_eq!(walled_in.stats.discovered, 1);
+    }
+
+    #[test]
+    fn dijkstra_takes_the_cheap_way_round() {
+        // straight from a to d is dear, going through b and c is cheap
+        let edges = |&node: &char| match node {
+            'a' => vec![('d', 10), ('b', 1)],
+            'b' => vec![('c', 2)],
+            'c' => vec![('d', 3)],
+            _ => vec![],
+        };
+        let found = dijkstra(['a'], edges, |&node| node == 'd').found.unwrap();
+        assert_eq!(found.cost, 6);
+        assert_eq!(found.path, ['a', 'b', 'c', 'd']);
+    }
+}
@@ -0,0 +1,457 @@
+use itertools::Itertools;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{char, multispace0},
+    combinator::{all_consuming, map},
+    error::{Error, ErrorKind},
+    multi::separated_list0,
+    sequence::{delimited, separated_pair},
+    IResult,
+};
+use thiserror::Error;
+
+use crate::output::json_string;
+use crate::{Answers, DayResult};
+
+#[derive(Debug, Error, Eq, PartialEq)]
+pub enum SerialiseError {
+    #[error("Malformed JSON at column {0}")]
+    Json(usize),
+    #[error("Expected {0}")]
+    Expected(&'static str),
+    #[error("Unknown answer type {0:?}")]
+    UnknownType(String),
+    #[error("{value:?} isn't a valid {variant} answer")]
+    InvalidValue { variant: String, value: String },
+    #[error("Line {line}: {error}")]
+    Line {
+        line: usize,
+        error: Box<SerialiseError>,
+    },
+}
+
+impl Answers {
+    /// The answer as JSON, like `{"type":"U64","value":"157"}`. The value is the canonical form
+    /// as a string, so big integers survive readers that treat numbers as floats, except for
+    /// lists, which are an array of answers, and grids, which are an array of rows.
+    pub fn to_json(&self) -> String {
+        let value = match self {
+            Answers::List(items) => format!("[{}]", items.iter().map(Answers::to_json).join(",")),
+            Answers::Grid(rows) => {
+                format!("[{}]", rows.iter().map(|row| json_string(row)).join(","))
+            }
+            answer => json_string(&answer.to_string()),
+        };
+        format!(
+            r#"{{"type":{},"value":{value}}}"#,
+            json_string(self.variant_name())
+        )
+    }
+
+    pub fn from_json(json: &str) -> Result<Answers, SerialiseError> {
+        answer_from_json(&parse_json(json)?)
+    }
+
+    /// The answer as tab separated fields: the variant and its escaped canonical form, or for a
+    /// list `List`, the number of items and then each item.
+    fn write_text(&self, out: &mut String) {
+        match self {
+            Answers::List(items) => {
+                out.push_str(&format!("List\t{}", items.len()));
+                for item in items {
+                    out.push('\t');
+                    item.write_text(out);
+                }
+            }
+            answer => {
+                out.push_str(answer.variant_name());
+                out.push('\t');
+                out.push_str(&escape(&answer.to_string()));
+            }
+        }
+    }
+
+    fn read_text<'a>(
+        fields: &mut impl Iterator<Item = &'a str>,
+    ) -> Result<Answers, SerialiseError> {
+        let variant = fields
+            .next()
+            .ok_or(SerialiseError::Expected("an answer type"))?;
+        if variant == "List" {
+            let count = fields
+                .next()
+                .and_then(|count| count.parse().ok())
+                .ok_or(SerialiseError::Expected("the number of items in the list"))?;
+            return (0..count)
+                .map(|_| Answers::read_text(fields))
+                .collect::<Result<_, _>>()
+                .map(Answers::List);
+        }
+
+        let value = unescape(
+            fields
+                .next()
+                .ok_or(SerialiseError::Expected("an answer after its type"))?,
+        )?;
+        match variant {
+            "Grid" => Ok(Answers::grid(value.lines())),
+            variant => Answers::from_canonical(variant, &value),
+        }
+    }
+}
+
+impl DayResult {
+    /// The result as a JSON object, like `{"part1":{"type":"U64","value":"157"},"part2":null}`.
+    pub fn to_json(&self) -> String {
+        let [part1, part2] = [&self.part1, &self.part2].map(|part| {
+            part.as_ref()
+                .map_or_else(|| "null".to_string(), Answers::to_json)
+        });
+        format!(r#"{{"part1":{part1},"part2":{part2}}}"#)
+    }
+
+    pub fn from_json(json: &str) -> Result<DayResult, SerialiseError> {
+        let expected = || SerialiseError::Expected("an object with part1 and part2");
+        let Json::Object(fields) = parse_json(json)? else {
+            return Err(expected());
+        };
+        let part = |name| match fields.iter().find(|(key, _)| key == name) {
+            Some((_, Json::Null)) => Ok(None),
+            Some((_, answer)) => answer_from_json(answer).map(Some),
+            None => Err(expected()),
+        };
+        Ok(DayResult {
+            part1: part("part1")?,
+            part2: part("part2")?,
+        })
+    }
+
+    /// The result with a line per answered part, like `part1\tU64\t157`. Tabs, newlines and
+    /// backslashes in answers are escaped, so each answer stays on its own line.
+    pub fn to_text(&self) -> String {
+        let mut out = String::new();
+        for (name, part) in [("part1", &self.part1), ("part2", &self.part2)] {
+            if let Some(answer) = part {
+                out.push_str(name);
+                out.push('\t');
+                answer.write_text(&mut out);
+                out.push('\n');
+            }
+        }
+        out
+    }
+
+    pub fn from_text(text: &str) -> Result<DayResult, SerialiseError> {
+        let mut result = DayResult {
+            part1: None,
+            part2: None,
+        };
+        for (i, line) in text.lines().enumerate() {
+            if line.is_empty() {
+                continue;
+            }
+            let mut read_line = || {
+                let mut fields = line.split('\t');
+                let part = match fields.next() {
+                    Some("part1") => &mut result.part1,
+                    Some("part2") => &mut result.part2,
+                    _ => {
+                        return Err(SerialiseError::Expected(
+                            "a line starting with part1 or part2",
+                        ))
+                    }
+                };
+                if part.is_some() {
+                    return Err(SerialiseError::Expected("each part at most once"));
+                }
+                *part = Some(Answers::read_text(&mut fields)?);
+                match fields.next() {
+                    Some(_) => Err(SerialiseError::Expected("the line to end after the answer")),
+                    None => Ok(()),
+                }
+            };
+            read_line().map_err(|error| SerialiseError::Line {
+                line: i + 1,
+                error: Box::new(error),
+            })?;
+        }
+        Ok(result)
+    }
+}
+
+fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '\t' => out.push_str("\\t"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+fn unescape(s: &str) -> Result<String, SerialiseError> {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        out.push(match chars.next() {
+            Some('\\') => '\\',
+            Some('t') => '\t',
+            Some('n') => '\n',
+            Some('r') => '\r',
+            _ => {
+                return Err(SerialiseError::Expected(
+                    "an escape like \\n, \\t, \\r or \\\\",
+                ))
+            }
+        });
+    }
+    Ok(out)
+}
+
+/// The parts of JSON that answers are written with.
+#[derive(Debug)]
+enum Json {
+    Null,
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+fn answer_from_json(json: &Json) -> Result<Answers, SerialiseError> {
+    let expected =
+        || SerialiseError::Expected("an answer like {\"type\":\"U64\",\"value\":\"157\"}");
+    let Json::Object(fields) = json else {
+        return Err(expected());
+    };
+    let field = |name| {
+        fields
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value)
+    };
+    let (Some(Json::String(variant)), Some(value)) = (field("type"), field("value")) else {
+        return Err(expected());
+    };
+
+    match (variant.as_str(), value) {
+        ("List", Json::Array(items)) => items
+            .iter()
+            .map(answer_from_json)
+            .collect::<Result<_, _>>()
+            .map(Answers::List),
+        ("Grid", Json::Array(rows)) => rows
+            .iter()
+            .map(|row| match row {
+                Json::String(row) => Ok(row.clone()),
+                _ => Err(SerialiseError::Expected("the rows of a grid to be strings")),
+            })
+            .collect::<Result<_, _>>()
+            .map(Answers::Grid),
+        (variant, Json::String(value)) => Answers::from_canonical(variant, value),
+        _ => Err(expected()),
+    }
+}
+
+fn parse_json(input: &str) -> Result<Json, SerialiseError> {
+    match all_consuming(json_value)(input) {
+        Ok((_, json)) => Ok(json),
+        Err(nom::Err::Error(err) | nom::Err::Failure(err)) => {
+            Err(SerialiseError::Json(input.len() - err.input.len() + 1))
+        }
+        Err(nom::Err::Incomplete(_)) => Err(SerialiseError::Json(input.len() + 1)),
+    }
+}
+
+/// A value, with any whitespace around it.
+fn json_value(input: &str) -> IResult<&str, Json> {
+    delimited(
+        multispace0,
+        alt((
+            map(tag("null"), |_| Json::Null),
+            map(json_string_value, Json::String),
+            map(
+                delimited(
+                    char('['),
+                    separated_list0(char(','), json_value),
+                    pair_end(']'),
+                ),
+                Json::Array,
+            ),
+            map(
+                delimited(
+                    char('{'),
+                    separated_list0(
+                        char(','),
+                        separated_pair(
+                            delimited(multispace0, json_string_value, multispace0),
+                            char(':'),
+                            json_value,
+                        ),
+                    ),
+                    pair_end('}'),
+                ),
+                Json::Object,
+            ),
+        )),
+        multispace0,
+    )(input)
+}
+
+/// The closing bracket of an array or object, which may be empty and contain whitespace.
+fn pair_end(close: char) -> impl Fn(&str) -> IResult<&str, char> {
+    move |input| delimited(multispace0, char(close), multispace0)(input)
+}
+
+fn json_string_value(input: &str) -> IResult<&str, String> {
+    let fail = |at| nom::Err::Error(Error::new(at, ErrorKind::Char));
+    let Some(rest) = input.strip_prefix('"') else {
+        return Err(fail(input));
+    };
+
+    let mut out = String::new();
+    let mut chars = rest.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Ok((&rest[i + 1..], out)),
+            '\\' => {
+                let Some((j, escaped)) = chars.next() else {
+                    break;
+                };
+                out.push(match escaped {
+                    '"' | '\\' | '/' => escaped,
+                    'n' => '\n',
+                    'r' => '\r',
+                    't' => '\t',
+                    'b' => '\u{8}',
+                    'f' => '\u{c}',
+                    'u' => {
+                        let code = rest
+                            .get(j + 1..j + 5)
+                            .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                            .and_then(char::from_u32)
+                            .ok_or_else(|| fail(&rest[j..]))?;
+                        chars.nth(3);
+                        code
+                    }
+                    _ => return Err(fail(&rest[j..])),
+                });
+            }
+            c => out.push(c),
+        }
+    }
+    Err(fail(""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SerialiseError;
+    use crate::{Answers, Day10Result, DayResult};
+    use num::bigint::BigInt;
+
+    fn results() -> Vec<DayResult> {
+        let screen = format!("{}\n", "#".repeat(40)).repeat(6);
+        vec![
+            DayResult {
+                part1: Some(157_u64.into()),
+                part2: None,
+            },
+            DayResult {
+                part1: Some(Answers::I128(-3)),
+                part2: Some(BigInt::from(10).pow(40).into()),
+            },
+            DayResult {
+                part1: Some("say \"hi\"\tthen\\\n\u{1}".into()),
+                part2: Some(Answers::list([
+                    Answers::from(1_u8),
+                    Answers::from("a,b"),
+                    Answers::list(Vec::<Answers>::new()),
+                    Answers::grid(["#.", ""]),
+                ])),
+            },
+            DayResult {
+                part1: Some(Answers::grid(["#.", ".#"])),
+                part2: Some(screen.parse::<Day10Result>().unwrap().into()),
+            },
+        ]
+    }
+
+    #[test]
+    fn results_round_trip() {
+        for result in results() {
+            let json = result.to_json();
+            let parsed = DayResult::from_json(&json).unwrap();
+            assert_eq!(parsed, result, "{json}");
+            assert_eq!(parsed.to_json(), json);
+
+            let text = result.to_text();
+            let parsed = DayResult::from_text(&text).unwrap();
+            assert_eq!(parsed, result, "{text}");
+            assert_eq!(parsed.to_text(), text);
+        }
+    }
+
+    #[test]
+    fn formats_are_stable() {
+        let result = DayResult {
+            part1: Some(157_u64.into()),
+            part2: Some(Answers::list([1_u32, 2])),
+        };
+        assert_eq!(
+            result.to_json(),
+            r#"{"part1":{"type":"U64","value":"157"},"part2":{"type":"List","value":[{"type":"U32","value":"1"},{"type":"U32","value":"2"}]}}"#
+        );
+        assert_eq!(
+            result.to_text(),
+            "part1\tU64\t157\npart2\tList\t2\tU32\t1\tU32\t2\n"
+        );
+
+        let spaced =
+            r##" { "part1" : { "type" : "Grid" , "value" : [ "#", "." ] } , "part2" : null } "##;
+        assert_eq!(
+            DayResult::from_json(spaced).unwrap(),
+            DayResult {
+                part1: Some(Answers::grid(["#", "."])),
+                part2: None,
+            }
+        );
+    }
+
+    #[test]
+    fn bad_results_are_rejected() {
+        assert_eq!(
+            DayResult::from_json(r#"{"part1":null,"part2":"#),
+            Err(SerialiseError::Json(14))
+        );
+        assert_eq!(
+            Answers::from_json(r#"{"type":"U8","value":"256"}"#),
+            Err(SerialiseError::InvalidValue {
+                variant: "U8".to_string(),
+                value: "256".to_string()
+            })
+        );
+        assert_eq!(
+            Answers::from_json(r#"{"type":"Float","value":"1.5"}"#),
+            Err(SerialiseError::UnknownType("Float".to_string()))
+        );
+        assert_eq!(
+            DayResult::from_text("part1\tU64\t157\npart3\tU64\t1\n"),
+            Err(SerialiseError::Line {
+                line: 2,
+                error: Box::new(SerialiseError::Expected(
+                    "a line starting with part1 or part2"
+                ))
+            })
+        );
+        assert!(DayResult::from_text("part1\tList\t2\tU32\t1\n").is_err());
+        assert!(DayResult::from_text("part1\tU64\t157\tU64\n").is_err());
+    }
+}
@@ -0,0 +1,87 @@
+use std::cell::OnceCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+use std::time::Duration;
+
+use thiserror::Error;
+
+use crate::catch_panic;
+
+thread_local! {
+    static CANCELLED: OnceCell<Arc<AtomicBool>> = const { OnceCell::new() };
+}
+
+#[derive(Debug, Error, Eq, PartialEq)]
+#[error("Cancelled after running out of time")]
+pub struct Cancelled;
+
+/// Whether the day running on this thread has run out of time. Long searches check this so the
+/// worker can finish soon after a timeout; whatever they return afterwards is thrown away.
+pub fn is_cancelled() -> bool {
+    CANCELLED.with(|cancelled| {
+        cancelled
+            .get()
+            .is_some_and(|cancelled| cancelled.load(Ordering::Relaxed))
+    })
+}
+
+/// Like [`is_cancelled`], for solvers that can bail out with an error.
+pub fn check_cancelled() -> Result<(), Cancelled> {
+    if is_cancelled() {
+        Err(Cancelled)
+    } else {
+        Ok(())
+    }
+}
+
+/// Runs `f` on a worker thread, giving up on it after `limit`. Returns `None` on timeout, after
+/// asking the worker to cancel. Panics in `f` are returned as errors.
+pub fn run_with_timeout<T: Send + 'static>(
+    limit: Duration,
+    f: impl FnOnce() -> anyhow::Result<T> + Send + 'static,
+) -> Option<anyhow::Result<T>> {
+    let cancelled = Arc::new(AtomicBool::new(false));
+    let (tx, rx) = mpsc::channel();
+
+    let flag = Arc::clone(&cancelled);
+    std::thread::spawn(move || {
+        CANCELLED.with(|cancelled| cancelled.set(flag).expect("fresh thread"));
+        // the receiver is gone if we've already timed out
+        let _ = tx.send(catch_panic(f));
+    });
+
+    match rx.recv_timeout(limit) {
+        Ok(result) => Some(result),
+        Err(_) => {
+            cancelled.store(true, Ordering::Relaxed);
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_cancelled, run_with_timeout};
+    use std::time::Duration;
+
+    #[test]
+    fn slow_days_are_cancelled() {
+        assert_eq!(
+            run_with_timeout(Duration::from_secs(10), || Ok(3)).map(|r| r.unwrap()),
+            Some(3)
+        );
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        let result = run_with_timeout(Duration::from_millis(10), move || {
+            while !is_cancelled() {
+                std::thread::sleep(Duration::from_millis(1));
+            }
+            tx.send(()).unwrap();
+            Ok(())
+        });
+        assert!(result.is_none());
+        // the worker notices it was cancelled and stops
+        rx.recv_timeout(Duration::from_secs(10)).unwrap();
+        assert!(!is_cancelled());
+    }
+}
@@ -0,0 +1,109 @@
+use std::time::Duration;
+
+/// How many times to run each day, and how many of those runs to throw away first.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Repeat {
+    pub warmup: usize,
+    pub runs: usize,
+}
+
+impl Default for Repeat {
+    fn default() -> Self {
+        Repeat { warmup: 0, runs: 1 }
+    }
+}
+
+/// Summary statistics over the timed runs of a day.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Stats> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let runs = sorted.len();
+        let min = sorted[0];
+        let median = if runs.is_multiple_of(2) {
+            (sorted[runs / 2 - 1] + sorted[runs / 2]) / 2
+        } else {
+            sorted[runs / 2]
+        };
+        // nearest rank
+        let p95 = sorted[(runs * 95).div_ceil(100) - 1];
+
+        let total: Duration = sorted.iter().sum();
+        let mean = total / runs as u32;
+
+        let stddev = if runs > 1 {
+            let mean_ns = mean.as_nanos() as f64;
+            let variance = sorted
+                .iter()
+                .map(|d| (d.as_nanos() as f64 - mean_ns).powi(2))
+                .sum::<f64>()
+                / (runs - 1) as f64;
+            Duration::from_nanos(variance.sqrt() as u64)
+        } else {
+            Duration::ZERO
+        };
+
+        Some(Stats {
+            runs,
+            min,
+            median,
+            mean,
+            p95,
+            stddev,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Stats;
+    use std::time::Duration;
+
+    #[test]
+    fn single_sample_has_no_spread() {
+        let d = Duration::from_micros(7);
+        assert_eq!(
+            Stats::from_samples(&[d]),
+            Some(Stats {
+                runs: 1,
+                min: d,
+                median: d,
+                mean: d,
+                p95: d,
+                stddev: Duration::ZERO,
+            })
+        );
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn stats_over_many_samples() {
+        let samples = (1..=20)
+            .rev()
+            .map(Duration::from_millis)
+            .collect::<Vec<_>>();
+        let stats = Stats::from_samples(&samples).unwrap();
+        assert_eq!(stats.runs, 20);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(10_500));
+        assert_eq!(stats.mean, Duration::from_micros(10_500));
+        assert_eq!(stats.p95, Duration::from_millis(19));
+        // sample standard deviation of 1..=20 is ~5.916
+        assert_eq!(stats.stddev.as_micros(), 5_916);
+    }
+}
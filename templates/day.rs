@@ -0,0 +1,59 @@
+use crate::params::{DayParams, Param};
+use crate::{Answers, Day, IntoAnswer};
+
+pub struct Day{day};
+
+pub const PARAMS: &[Param] = &[];
+
+impl Day for Day{day} {
+    type Parsed<'a> = &'a str;
+
+    fn parse<'a>(input: &'a str, _params: &DayParams) -> anyhow::Result<Self::Parsed<'a>> {
+        Ok(input)
+    }
+
+    fn part1(_parsed: &Self::Parsed<'_>, _params: &DayParams) -> anyhow::Result<Option<Answers>> {
+        ().into_answer()
+    }
+
+    fn part2(_parsed: &Self::Parsed<'_>, _params: &DayParams) -> anyhow::Result<Option<Answers>> {
+        ().into_answer()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day{day}, PARAMS};
+    use crate::params::DayParams;
+    use crate::{Day, DayResult};
+
+    #[test]
+    fn test_example_answers() {
+        let result = Day{day}::solve(
+            include_str!("../../../input/{year}/test/{day}.txt"),
+            &DayParams::defaults(PARAMS, true),
+        );
+        assert_eq!(
+            result.unwrap(),
+            DayResult {
+                part1: None,
+                part2: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_answers() {
+        let result = Day{day}::solve(
+            include_str!("../../../input/{year}/real/{day}.txt"),
+            &DayParams::defaults(PARAMS, false),
+        );
+        assert_eq!(
+            result.unwrap(),
+            DayResult {
+                part1: None,
+                part2: None,
+            }
+        );
+    }
+}